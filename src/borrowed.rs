@@ -0,0 +1,470 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Formatter;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::bounds::Bounds;
+use crate::center::Center;
+use crate::format::{RasterDemEncoding, TileFormat};
+use crate::tile_size::TileSize;
+use crate::tilejson::TileJSON;
+use crate::tilestats::{GeometryType, Tilestats};
+use crate::vector_layer::VectorLayer;
+use crate::zoom::Zoom;
+
+/// A borrowed variant of [`TileJSON`] that deserializes strings without allocating.
+///
+/// The `tiles` URLs, names, descriptions and vector layer fields borrow from the input
+/// whenever possible, i.e. unless a string contains escape sequences.
+/// Use it to parse and re-serve many documents, and convert it into the owned [`TileJSON`]
+/// with [`into_owned`](Self::into_owned) when it must outlive the input.
+/// Less common fields like `tilestats` and the unrecognized fields in `other` are owned.
+/// See [`TileJSON`] for the meaning of each field.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use tilejson::{TileJSON, TileJSONRef};
+/// let json = r#"{"tilejson": "3.0.0", "tiles": ["https://example.com/{z}/{x}/{y}.pbf"]}"#;
+/// let tj: TileJSONRef = serde_json::from_str(json).unwrap();
+/// assert!(matches!(tj.tiles[0], Cow::Borrowed(_)));
+///
+/// let owned: TileJSON = tj.into_owned();
+/// assert_eq!(owned, serde_json::from_str::<TileJSON>(json).unwrap());
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct TileJSONRef<'a> {
+    #[serde(borrow)]
+    pub tilejson: Cow<'a, str>,
+
+    #[serde(borrow, deserialize_with = "deserialize_vec")]
+    pub tiles: Vec<Cow<'a, str>>,
+
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub vector_layers: Option<Vec<VectorLayerRef<'a>>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub attribution: Option<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<Bounds>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub center: Option<Center>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt_vec",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data: Option<Vec<Cow<'a, str>>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fillzoom: Option<Zoom>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt_vec",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grids: Option<Vec<Cow<'a, str>>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub legend: Option<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<Zoom>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<Zoom>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Cow<'a, str>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scheme: Option<Cow<'a, str>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub template: Option<Cow<'a, str>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub version: Option<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tilestats: Option<Tilestats>,
+
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bearing: Option<f64>,
+
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pitch: Option<f64>,
+
+    #[serde(
+        rename = "tileSize",
+        alias = "tile_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tile_size: Option<TileSize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<TileFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<RasterDemEncoding>,
+
+    /// Any unrecognized fields will be stored here
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// A borrowed variant of [`VectorLayer`], part of a [`TileJSONRef`].
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct VectorLayerRef<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+
+    #[serde(borrow, deserialize_with = "deserialize_map")]
+    pub fields: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "deserialize_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Cow<'a, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxzoom: Option<Zoom>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minzoom: Option<Zoom>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<GeometryType>,
+
+    /// Any unrecognized fields will be stored here.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+impl TileJSONRef<'_> {
+    /// Convert into the owned [`TileJSON`], copying only the borrowed strings.
+    #[must_use]
+    pub fn into_owned(self) -> TileJSON {
+        TileJSON {
+            tilejson: self.tilejson.into_owned(),
+            tiles: into_owned_vec(self.tiles),
+            vector_layers: self
+                .vector_layers
+                .map(|v| v.into_iter().map(VectorLayerRef::into_owned).collect()),
+            attribution: self.attribution.map(Cow::into_owned),
+            bounds: self.bounds,
+            center: self.center,
+            data: self.data.map(into_owned_vec),
+            description: self.description.map(Cow::into_owned),
+            fillzoom: self.fillzoom,
+            grids: self.grids.map(into_owned_vec),
+            legend: self.legend.map(Cow::into_owned),
+            maxzoom: self.maxzoom,
+            minzoom: self.minzoom,
+            name: self.name.map(Cow::into_owned),
+            scheme: self.scheme.map(Cow::into_owned),
+            template: self.template.map(Cow::into_owned),
+            version: self.version.map(Cow::into_owned),
+            tilestats: self.tilestats,
+            bearing: self.bearing,
+            pitch: self.pitch,
+            tile_size: self.tile_size,
+            format: self.format,
+            encoding: self.encoding,
+            other: self.other,
+        }
+    }
+}
+
+impl VectorLayerRef<'_> {
+    /// Convert into the owned [`VectorLayer`], copying only the borrowed strings.
+    #[must_use]
+    pub fn into_owned(self) -> VectorLayer {
+        VectorLayer {
+            id: self.id.into_owned(),
+            fields: self
+                .fields
+                .into_iter()
+                .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                .collect(),
+            description: self.description.map(Cow::into_owned),
+            maxzoom: self.maxzoom,
+            minzoom: self.minzoom,
+            geometry: self.geometry,
+            other: self.other,
+        }
+    }
+}
+
+impl From<TileJSONRef<'_>> for TileJSON {
+    fn from(value: TileJSONRef<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl From<VectorLayerRef<'_>> for VectorLayer {
+    fn from(value: VectorLayerRef<'_>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl<'a> From<&'a TileJSON> for TileJSONRef<'a> {
+    /// Borrow all strings of an owned document, e.g. to serve it together with parsed ones.
+    fn from(value: &'a TileJSON) -> Self {
+        Self {
+            tilejson: Cow::Borrowed(&value.tilejson),
+            tiles: borrow_vec(&value.tiles),
+            vector_layers: value
+                .vector_layers
+                .as_ref()
+                .map(|v| v.iter().map(VectorLayerRef::from).collect()),
+            attribution: value.attribution.as_deref().map(Cow::Borrowed),
+            bounds: value.bounds,
+            center: value.center,
+            data: value.data.as_deref().map(borrow_vec),
+            description: value.description.as_deref().map(Cow::Borrowed),
+            fillzoom: value.fillzoom,
+            grids: value.grids.as_deref().map(borrow_vec),
+            legend: value.legend.as_deref().map(Cow::Borrowed),
+            maxzoom: value.maxzoom,
+            minzoom: value.minzoom,
+            name: value.name.as_deref().map(Cow::Borrowed),
+            scheme: value.scheme.as_deref().map(Cow::Borrowed),
+            template: value.template.as_deref().map(Cow::Borrowed),
+            version: value.version.as_deref().map(Cow::Borrowed),
+            tilestats: value.tilestats.clone(),
+            bearing: value.bearing,
+            pitch: value.pitch,
+            tile_size: value.tile_size,
+            format: value.format.clone(),
+            encoding: value.encoding.clone(),
+            other: value.other.clone(),
+        }
+    }
+}
+
+impl<'a> From<&'a VectorLayer> for VectorLayerRef<'a> {
+    fn from(value: &'a VectorLayer) -> Self {
+        Self {
+            id: Cow::Borrowed(&value.id),
+            fields: value
+                .fields
+                .iter()
+                .map(|(k, v)| (Cow::Borrowed(k.as_str()), Cow::Borrowed(v.as_str())))
+                .collect(),
+            description: value.description.as_deref().map(Cow::Borrowed),
+            maxzoom: value.maxzoom,
+            minzoom: value.minzoom,
+            geometry: value.geometry.clone(),
+            other: value.other.clone(),
+        }
+    }
+}
+
+fn into_owned_vec(values: Vec<Cow<'_, str>>) -> Vec<String> {
+    values.into_iter().map(Cow::into_owned).collect()
+}
+
+fn borrow_vec(values: &[String]) -> Vec<Cow<'_, str>> {
+    values.iter().map(|v| Cow::Borrowed(v.as_str())).collect()
+}
+
+/// A string that borrows from the input if possible.
+///
+/// Serde only borrows a `Cow<str>` field directly, so nested ones like `Vec<Cow<str>>`
+/// would always be copied without it.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct CowStr<'a>(Cow<'a, str>);
+
+impl<'de> Deserialize<'de> for CowStr<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CowStrVisitor;
+
+        impl<'de> Visitor<'de> for CowStrVisitor {
+            type Value = CowStr<'de>;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v.to_string())))
+            }
+
+            fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+fn deserialize_opt<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'de, str>>, D::Error> {
+    Option::<CowStr>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
+
+fn deserialize_vec<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Cow<'de, str>>, D::Error> {
+    Vec::<CowStr>::deserialize(deserializer).map(|v| v.into_iter().map(|v| v.0).collect())
+}
+
+fn deserialize_opt_vec<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<Cow<'de, str>>>, D::Error> {
+    Option::<Vec<CowStr>>::deserialize(deserializer)
+        .map(|v| v.map(|v| v.into_iter().map(|v| v.0).collect()))
+}
+
+fn deserialize_map<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<Cow<'de, str>, Cow<'de, str>>, D::Error> {
+    BTreeMap::<CowStr, CowStr>::deserialize(deserializer)
+        .map(|v| v.into_iter().map(|(k, v)| (k.0, v.0)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[allow(clippy::ptr_arg)]
+    fn is_borrowed(value: &Cow<'_, str>) -> bool {
+        matches!(value, Cow::Borrowed(_))
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let json = r#"{
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/{z}/{x}/{y}.pbf", "https://example.com/\u00e9/{z}/{x}/{y}.pbf"],
+            "name": "roads",
+            "description": "say \"hi\"",
+            "vector_layers": [{"id": "roads", "fields": {"name": "String", "class": "String"}}]
+        }"#;
+        let tj: TileJSONRef = serde_json::from_str(json).unwrap();
+        assert!(is_borrowed(&tj.tilejson));
+        assert!(is_borrowed(&tj.tiles[0]));
+        assert!(!is_borrowed(&tj.tiles[1]));
+        assert!(is_borrowed(tj.name.as_ref().unwrap()));
+        assert!(!is_borrowed(tj.description.as_ref().unwrap()));
+        let layer = &tj.vector_layers.as_ref().unwrap()[0];
+        assert!(is_borrowed(&layer.id));
+        assert!(layer
+            .fields
+            .iter()
+            .all(|(k, v)| is_borrowed(k) && is_borrowed(v)));
+        assert_eq!(tj.tiles[1], "https://example.com/é/{z}/{x}/{y}.pbf");
+        assert_eq!(tj.description.as_deref(), Some("say \"hi\""));
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let value = json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/{z}/{x}/{y}.png"],
+            "vector_layers": [{"id": "a", "fields": {}, "description": "A", "maxzoom": 4, "x": 1}],
+            "attribution": "attr",
+            "bounds": [-180.0, -85.0, 180.0, 85.0],
+            "center": [1.5, 2.5, 3],
+            "data": ["https://example.com/data.geojson"],
+            "fillzoom": 5,
+            "grids": ["https://example.com/{z}/{x}/{y}.grid.json"],
+            "legend": "legend",
+            "minzoom": 1,
+            "maxzoom": 14,
+            "scheme": "xyz",
+            "template": "template",
+            "version": "1.0.0",
+            "bearing": 10.5,
+            "pitch": 20.5,
+            "tileSize": 512,
+            "format": "png",
+            "encoding": "terrarium",
+            "x-custom": {"a": [1, 2]}
+        });
+        let json = value.to_string();
+        let expected: TileJSON = serde_json::from_str(&json).unwrap();
+        let tj: TileJSONRef = serde_json::from_str(&json).unwrap();
+        assert_eq!(TileJSONRef::from(&expected), tj);
+        assert_eq!(serde_json::to_value(&tj).unwrap(), value);
+        assert_eq!(TileJSON::from(tj), expected);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(serde_json::from_str::<TileJSONRef>(r#"{"tilejson": "3.0.0"}"#).is_err());
+        assert!(
+            serde_json::from_str::<TileJSONRef>(r#"{"tilejson": "3.0.0", "tiles": [1]}"#).is_err()
+        );
+        assert!(serde_json::from_str::<TileJSONRef>(
+            r#"{"tilejson": "3.0.0", "tiles": [], "vector_layers": [{"id": "a"}]}"#
+        )
+        .is_err());
+    }
+}
@@ -3,12 +3,16 @@ use std::num::ParseFloatError;
 use std::ops::{Add, AddAssign};
 use std::str::FromStr;
 
-use serde_tuple::{Deserialize_tuple, Serialize_tuple};
+use serde::de::{IgnoredAny, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_tuple::Serialize_tuple;
 use thiserror::Error;
 
+use crate::json_error::DomainError;
+use crate::number::LooseF64;
 use crate::ParseBoundsError::BadLen;
 
-#[derive(Serialize_tuple, Deserialize_tuple, PartialEq, Debug, Copy, Clone)]
+#[derive(Serialize_tuple, PartialEq, Debug, Copy, Clone)]
 pub struct Bounds {
     pub left: f64,
     pub bottom: f64,
@@ -64,6 +68,159 @@ impl Bounds {
             top: 85.051_128_779_806_6,
         }
     };
+
+    /// Returns `true` if these bounds wrap around the antimeridian, i.e. `left > right`.
+    ///
+    /// Note that the spec does not allow such bounds, but they are sometimes produced
+    /// by tile servers for data that spans the 180th meridian.
+    ///
+    /// ```
+    /// # use tilejson::Bounds;
+    /// assert!(Bounds::new(170., -10., -170., 10.).crosses_antimeridian());
+    /// assert!(!Bounds::new(-170., -10., 170., 10.).crosses_antimeridian());
+    /// ```
+    #[must_use]
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.left > self.right
+    }
+
+    /// Combine two bounds into a bounding box that encloses both, without crossing the antimeridian,
+    /// as required by the spec.
+    ///
+    /// Bounds that [cross the antimeridian](Self::crosses_antimeridian) can only be enclosed
+    /// by the full longitude range. Use [`Bounds::union_wrapping`] to get the narrowest bounds,
+    /// which may cross the antimeridian.
+    ///
+    /// ```
+    /// # use tilejson::Bounds;
+    /// assert_eq!(
+    ///     Bounds::new(1., 3., 7., 9.).union(Bounds::new(2., 2., 8., 8.)),
+    ///     Bounds::new(1., 2., 8., 9.)
+    /// );
+    /// assert_eq!(
+    ///     Bounds::new(170., 0., 180., 10.).union(Bounds::new(-180., -5., -160., 5.)),
+    ///     Bounds::new(-180., -5., 180., 10.)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        let (left, right) = if self.crosses_antimeridian() || other.crosses_antimeridian() {
+            (-180., 180.)
+        } else {
+            (self.left.min(other.left), self.right.max(other.right))
+        };
+        Self::new(
+            left,
+            self.bottom.min(other.bottom),
+            right,
+            self.top.max(other.top),
+        )
+    }
+
+    /// Combine two bounds into the narrowest bounding box that encloses both,
+    /// which may [cross the antimeridian](Self::crosses_antimeridian).
+    ///
+    /// Unlike [`Bounds::union`], the result is the narrowest longitude range that covers both
+    /// bounds, e.g. when combining bounds on both sides of the 180th meridian.
+    /// Such bounds are not allowed in a `TileJSON` document, but are useful for computations.
+    ///
+    /// ```
+    /// # use tilejson::Bounds;
+    /// assert_eq!(
+    ///     Bounds::new(170., 0., 180., 10.).union_wrapping(Bounds::new(-180., -5., -160., 5.)),
+    ///     Bounds::new(170., -5., -160., 10.)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn union_wrapping(self, other: Self) -> Self {
+        // Treat longitude ranges as arcs on a circle, and pick the shorter of the two
+        // arcs that start at one of the ranges and extend far enough to cover the other one.
+        let width = |b: &Self| {
+            if b.crosses_antimeridian() {
+                b.right - b.left + 360.
+            } else {
+                b.right - b.left
+            }
+        };
+        let cover =
+            |a: &Self, b: &Self| width(a).max((b.left - a.left).rem_euclid(360.) + width(b));
+        let (left, arc) = if cover(&self, &other) <= cover(&other, &self) {
+            (self.left, cover(&self, &other))
+        } else {
+            (other.left, cover(&other, &self))
+        };
+        let (left, right) = if arc >= 360. {
+            (-180., 180.)
+        } else if left + arc > 180. {
+            (left, left + arc - 360.)
+        } else {
+            (left, left + arc)
+        };
+        Self::new(
+            left,
+            self.bottom.min(other.bottom),
+            right,
+            self.top.max(other.top),
+        )
+    }
+
+    /// Compute the area covered by both bounds, or `None` if they do not overlap.
+    ///
+    /// Bounds that only touch each other produce a zero-width or zero-height result.
+    /// Bounds that [cross the antimeridian](Self::crosses_antimeridian) are supported.
+    ///
+    /// ```
+    /// # use tilejson::Bounds;
+    /// assert_eq!(
+    ///     Bounds::new(0., 0., 10., 10.).intersection(Bounds::new(5., -5., 15., 5.)),
+    ///     Some(Bounds::new(5., 0., 10., 5.))
+    /// );
+    /// assert_eq!(
+    ///     Bounds::new(170., 0., -170., 10.).intersection(Bounds::new(-175., -5., 0., 5.)),
+    ///     Some(Bounds::new(-175., 0., -170., 5.))
+    /// );
+    /// assert_eq!(Bounds::new(0., 0., 1., 1.).intersection(Bounds::new(2., 2., 3., 3.)), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        // Split bounds crossing the antimeridian into two regular parts
+        let split = |b: Self| {
+            if b.crosses_antimeridian() {
+                [
+                    Some(Self::new(b.left, b.bottom, 180., b.top)),
+                    Some(Self::new(-180., b.bottom, b.right, b.top)),
+                ]
+            } else {
+                [Some(b), None]
+            }
+        };
+        let mut result: Option<Self> = None;
+        for a in split(self).into_iter().flatten() {
+            for b in split(other).into_iter().flatten() {
+                let part = Self::new(
+                    a.left.max(b.left),
+                    a.bottom.max(b.bottom),
+                    a.right.min(b.right),
+                    a.top.min(b.top),
+                );
+                if part.left <= part.right && part.bottom <= part.top {
+                    result = Some(result.map_or(part, |v| v.union_wrapping(part)));
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if the given coordinate is within these bounds, including the edges.
+    #[must_use]
+    pub fn contains(&self, longitude: f64, latitude: f64) -> bool {
+        let lon_ok = if self.crosses_antimeridian() {
+            longitude >= self.left || longitude <= self.right
+        } else {
+            self.left <= longitude && longitude <= self.right
+        };
+        lon_ok && self.bottom <= latitude && latitude <= self.top
+    }
 }
 
 impl Default for Bounds {
@@ -106,42 +263,28 @@ impl Add for Bounds {
     type Output = Bounds;
 
     /// Combine two bounds, resulting in an bounding box that encloses both.
+    /// Same as [`Bounds::union`], so the result spans all longitudes
+    /// if either of the bounds crosses the antimeridian.
     ///
     /// ```
     /// # use tilejson::Bounds;
     /// assert_eq!(
     ///     Bounds::new(1., 3., 7., 9.) + Bounds::new(2., 2., 8., 8.),
     ///     Bounds::new(1., 2., 8., 9.)
-    /// )
+    /// );
+    /// assert_eq!(
+    ///     Bounds::new(170., 0., -170., 1.) + Bounds::new(175., 0., 179., 1.),
+    ///     Bounds::new(-180., 0., 180., 1.)
+    /// );
     /// ```
     fn add(self, rhs: Self) -> Self::Output {
-        Self::Output::new(
-            if self.left < rhs.left {
-                self.left
-            } else {
-                rhs.left
-            },
-            if self.bottom < rhs.bottom {
-                self.bottom
-            } else {
-                rhs.bottom
-            },
-            if self.right > rhs.right {
-                self.right
-            } else {
-                rhs.right
-            },
-            if self.top > rhs.top {
-                self.top
-            } else {
-                rhs.top
-            },
-        )
+        self.union(rhs)
     }
 }
 
 impl AddAssign for Bounds {
     /// Combine another bounds into this one, resulting in an bounding box that encloses both.
+    /// Same as [`Bounds::union`].
     ///
     /// ```
     /// # use tilejson::Bounds;
@@ -150,18 +293,37 @@ impl AddAssign for Bounds {
     /// assert_eq!(value, Bounds::new(1., 2., 8., 9.))
     /// ```
     fn add_assign(&mut self, rhs: Self) {
-        if self.left > rhs.left {
-            self.left = rhs.left;
-        }
-        if self.bottom > rhs.bottom {
-            self.bottom = rhs.bottom;
-        }
-        if self.right < rhs.right {
-            self.right = rhs.right;
-        }
-        if self.top < rhs.top {
-            self.top = rhs.top;
+        *self = self.union(rhs);
+    }
+}
+
+impl<'de> Deserialize<'de> for Bounds {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BoundsVisitor;
+
+        impl<'de> Visitor<'de> for BoundsVisitor {
+            type Value = Bounds;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("an array of left, bottom, right, and top")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bounds, A::Error> {
+                let mut values = [0.0; 4];
+                for value in &mut values {
+                    *value = seq
+                        .next_element::<LooseF64>()?
+                        .ok_or_else(|| DomainError::BoundsLen.into_error::<A::Error>())?
+                        .0;
+                }
+                if seq.next_element::<IgnoredAny>()?.is_some() {
+                    return Err(DomainError::BoundsLen.into_error());
+                }
+                Ok(values.into())
+            }
         }
+
+        deserializer.deserialize_tuple(4, BoundsVisitor)
     }
 }
 
@@ -426,6 +588,94 @@ mod tests {
         assert!(matches!(err("0,0,0,1a"), ParseCoordError(_)));
     }
 
+    #[test]
+    fn test_union() {
+        let b = Bounds::new;
+        assert_eq!(
+            b(-10., 0., 10., 1.).union(b(20., -1., 30., 0.)),
+            b(-10., -1., 30., 1.)
+        );
+        let result = b(-180., -10., -170., 10.).union(b(170., -5., 180., 5.));
+        assert_eq!(result, b(-180., -10., 180., 10.));
+        assert!(!result.crosses_antimeridian());
+        assert_eq!(
+            b(170., 0., -170., 1.).union(b(175., 0., 179., 1.)),
+            b(-180., 0., 180., 1.)
+        );
+
+        // The operators are the same as `union`
+        assert_eq!(
+            b(170., 0., -170., 1.) + b(175., 0., 179., 1.),
+            b(-180., 0., 180., 1.)
+        );
+        let mut value = b(175., 0., 179., 1.);
+        value += b(170., 0., -170., 1.);
+        assert_eq!(value, b(-180., 0., 180., 1.));
+    }
+
+    #[test]
+    fn test_union_wrapping() {
+        let b = Bounds::new;
+        // regular bounds behave the same as the `+` operator
+        assert_eq!(
+            b(-10., 0., 10., 1.).union_wrapping(b(20., -1., 30., 0.)),
+            b(-10., -1., 30., 1.)
+        );
+        // one of the bounds crosses the antimeridian
+        assert_eq!(
+            b(170., 0., -170., 1.).union_wrapping(b(160., 0., 175., 1.)),
+            b(160., 0., -170., 1.)
+        );
+        assert_eq!(
+            b(-175., 0., -165., 1.).union_wrapping(b(170., 0., -170., 1.)),
+            b(170., 0., -165., 1.)
+        );
+        // contained bounds
+        assert_eq!(
+            b(170., 0., -170., 1.).union_wrapping(b(175., 0., 179., 1.)),
+            b(170., 0., -170., 1.)
+        );
+        // the result covers the whole world
+        assert_eq!(
+            b(0., 0., -10., 1.).union_wrapping(b(-20., 0., 5., 1.)),
+            b(-180., 0., 180., 1.)
+        );
+    }
+
+    #[test]
+    fn test_intersection() {
+        let b = Bounds::new;
+        assert_eq!(
+            b(0., 0., 10., 10.).intersection(b(10., 10., 20., 20.)),
+            Some(b(10., 10., 10., 10.))
+        );
+        assert_eq!(
+            b(0., 0., 10., 10.).intersection(b(2., 2., 3., 3.)),
+            Some(b(2., 2., 3., 3.))
+        );
+        assert_eq!(
+            b(170., 0., -170., 10.).intersection(b(160., 0., 175., 10.)),
+            Some(b(170., 0., 175., 10.))
+        );
+        assert_eq!(
+            b(170., 0., -170., 10.).intersection(b(-180., 0., 180., 10.)),
+            Some(b(170., 0., -170., 10.))
+        );
+        assert_eq!(
+            b(170., 0., -170., 10.).intersection(b(0., 0., 10., 10.)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let b = Bounds::new;
+        assert!(b(0., 0., 10., 10.).contains(0., 10.));
+        assert!(!b(0., 0., 10., 10.).contains(-1., 5.));
+        assert!(b(170., 0., -170., 10.).contains(-175., 5.));
+        assert!(!b(170., 0., -170., 10.).contains(0., 5.));
+    }
+
     #[test]
     #[allow(clippy::panic_in_result_fn)]
     fn test_from() -> Result<(), ParseBoundsError> {
@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter, Write as _};
+use std::num::ParseFloatError;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::center::{Center, ParseCenterError};
+use crate::TileJSON;
+
+/// The initial camera of a map: the [`Center`] with the optional `bearing` and `pitch`
+/// extensions, stored in the [`TileJSON::center`], [`TileJSON::bearing`],
+/// and [`TileJSON::pitch`] fields.
+#[derive(PartialEq, Debug, Default, Copy, Clone)]
+pub struct Camera {
+    pub center: Center,
+    /// Bearing in degrees, counter-clockwise from north.
+    pub bearing: Option<f64>,
+    /// Pitch in degrees, away from the plane of the screen.
+    pub pitch: Option<f64>,
+}
+
+impl Camera {
+    #[must_use]
+    pub fn new(center: Center, bearing: Option<f64>, pitch: Option<f64>) -> Self {
+        Self {
+            center,
+            bearing,
+            pitch,
+        }
+    }
+}
+
+impl From<Center> for Camera {
+    fn from(center: Center) -> Self {
+        Self::new(center, None, None)
+    }
+}
+
+impl Display for Camera {
+    /// Format camera as a comma-separated string of `lon,lat,zoom,bearing,pitch`.
+    /// Trailing missing values are omitted, and missing values in the middle are left empty.
+    /// Longitude, latitude, bearing, and pitch are formatted with specified precision parameters.
+    ///
+    /// ```
+    /// # use tilejson::{Camera, Center};
+    /// let camera = Camera::new(Center::new(1.5, -2.5, 8), Some(30.0), None);
+    /// assert_eq!(camera.to_string(), "1.5,-2.5,8,30");
+    /// assert_eq!(format!("{camera:.2}"), "1.50,-2.50,8,30.00");
+    /// let camera = Camera::new(Center::without_zoom(1.5, -2.5), None, Some(45.0));
+    /// assert_eq!(camera.to_string(), "1.5,-2.5,,,45");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.center.longitude.fmt(f)?;
+        f.write_char(',')?;
+        self.center.latitude.fmt(f)?;
+        let values = [self.center.zoom, self.bearing, self.pitch];
+        let len = values
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |v| v + 1);
+        for (idx, value) in values[..len].iter().enumerate() {
+            f.write_char(',')?;
+            match value {
+                // The zoom is not affected by the precision, same as in `Center`
+                Some(zoom) if idx == 0 => write!(f, "{zoom}")?,
+                Some(angle) => angle.fmt(f)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum ParseCameraError {
+    #[error("Incorrect number of values. Camera expects two to five f64 values.")]
+    BadLen,
+    /// Wrapped error from parsing the center values
+    #[error(transparent)]
+    ParseCenterError(#[from] ParseCenterError),
+    /// Wrapped error from the `parse::<f64>()` of the bearing or pitch
+    #[error(transparent)]
+    ParseAngleError(#[from] ParseFloatError),
+}
+
+impl FromStr for Camera {
+    type Err = ParseCameraError;
+
+    /// Parse a string of `lon,lat,zoom,bearing,pitch` comma-separated values, where the first
+    /// three values have the same format as [`Center::from_str`], and the last three are optional.
+    /// A missing value may be left empty, e.g. `1,2,,30` has a bearing but no zoom.
+    ///
+    /// ```
+    /// # use tilejson::{Camera, Center};
+    /// # use std::str::FromStr;
+    /// let camera = Camera::from_str("1.0, 2.0, 3, -20, 60").unwrap();
+    /// assert_eq!(camera, Camera::new(Center::new(1.0, 2.0, 3), Some(-20.0), Some(60.0)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vals: Vec<&str> = s.split(',').map(str::trim).collect();
+        if vals.len() > 5 {
+            return Err(ParseCameraError::BadLen);
+        }
+        let mut center = vals[..vals.len().min(3)].to_vec();
+        if center.get(2).is_some_and(|v| v.is_empty()) {
+            center.pop();
+        }
+        let parse = |idx: usize| {
+            vals.get(idx)
+                .filter(|v| !v.is_empty())
+                .map(|v| v.parse())
+                .transpose()
+        };
+        Ok(Self {
+            center: Center::from_str(&center.join(","))?,
+            bearing: parse(3)?,
+            pitch: parse(4)?,
+        })
+    }
+}
+
+impl TileJSON {
+    /// Get the initial camera, combining the `center`, `bearing`, and `pitch` values.
+    /// Returns `None` if the `center` is not set.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Center};
+    /// let tj = tilejson! { "https://example.com/".to_string(), center: Center::new(1., 2., 3), pitch: 45.0 };
+    /// let camera = tj.camera().unwrap();
+    /// assert_eq!(camera.center, Center::new(1., 2., 3));
+    /// assert_eq!((camera.bearing, camera.pitch), (None, Some(45.0)));
+    /// ```
+    #[must_use]
+    pub fn camera(&self) -> Option<Camera> {
+        self.center
+            .map(|center| Camera::new(center, self.bearing, self.pitch))
+    }
+
+    /// Set the `center`, `bearing`, and `pitch` values from the camera.
+    pub fn set_camera(&mut self, camera: Camera) {
+        self.center = Some(camera.center);
+        self.bearing = camera.bearing;
+        self.pitch = camera.pitch;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tilejson;
+
+    #[test]
+    fn test_parse() {
+        let val = |s| Camera::from_str(s).unwrap().to_string();
+        assert_eq!(val("1,2"), "1,2");
+        assert_eq!(val("1,2,3.5"), "1,2,3.5");
+        assert_eq!(val("1,2,3,4"), "1,2,3,4");
+        assert_eq!(val(" 1, 2, 3, 4, 5 "), "1,2,3,4,5");
+        assert_eq!(val("1,2,,30"), "1,2,,30");
+        assert_eq!(val("1,2,, ,45"), "1,2,,,45");
+        assert_eq!(val("1,2,3,,"), "1,2,3");
+
+        let camera = Camera::new(Center::without_zoom(1., 2.), Some(30.), None);
+        assert_eq!(camera.to_string(), "1,2,,30");
+        assert_eq!(Camera::from_str(&camera.to_string()), Ok(camera));
+
+        let err = |s| Camera::from_str(s).unwrap_err();
+        assert_eq!(err("1,2,3,4,5,6"), ParseCameraError::BadLen);
+        assert_eq!(
+            err("1"),
+            ParseCameraError::ParseCenterError(ParseCenterError::BadLen)
+        );
+        assert!(matches!(
+            err("1,2,3,a"),
+            ParseCameraError::ParseAngleError(_)
+        ));
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut tj = tilejson! { "https://example.com/".to_string() };
+        tj.set_camera("1,2,3,-20,60".parse().unwrap());
+        let json = serde_json::to_string(&tj).unwrap();
+        assert_eq!(
+            json,
+            r#"{"tilejson":"3.0.0","tiles":["https://example.com/"],"center":[1.0,2.0,3],"bearing":-20.0,"pitch":60.0}"#
+        );
+        let parsed: TileJSON = serde_json::from_str(&json).unwrap();
+        assert!(parsed.other.is_empty());
+        assert_eq!(parsed.camera(), tj.camera());
+    }
+}
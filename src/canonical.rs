@@ -0,0 +1,167 @@
+use std::fmt::Write as _;
+
+use serde_json::{Number, Value};
+
+use crate::TileJSON;
+
+/// Top-level arrays of endpoints that are de-duplicated in the canonical form
+const ENDPOINT_KEYS: &[&str] = &["tiles", "grids", "data"];
+
+impl TileJSON {
+    /// Serialize this document into a canonical JSON string, suitable for comparing
+    /// and hashing documents produced by different processes or library versions.
+    ///
+    /// * Object keys at every level, including the ones in `other`, are sorted.
+    /// * Numbers with no fractional part are written as integers, e.g. `3.0` becomes `3`,
+    ///   and other numbers use the shortest representation that round-trips.
+    /// * Duplicate `tiles`, `grids`, and `data` endpoints are removed, keeping the first one.
+    /// * No insignificant whitespace is written.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Bounds};
+    /// let tj = tilejson! {
+    ///     tiles: vec!["https://a/".to_string(), "https://a/".to_string()],
+    ///     name: "x".to_string(),
+    ///     bounds: Bounds::new(-1.0, -2.5, 3.0, 4.0),
+    /// };
+    /// assert_eq!(
+    ///     tj.to_canonical_string(),
+    ///     r#"{"bounds":[-1,-2.5,3,4],"name":"x","tilejson":"3.0.0","tiles":["https://a/"]}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_canonical_string(&self) -> String {
+        let mut value = self.to_json_value();
+        if let Value::Object(map) = &mut value {
+            for key in ENDPOINT_KEYS {
+                if let Some(Value::Array(endpoints)) = map.get_mut(*key) {
+                    let mut seen = Vec::with_capacity(endpoints.len());
+                    endpoints.retain(|v| {
+                        let is_new = !seen.contains(v);
+                        if is_new {
+                            seen.push(v.clone());
+                        }
+                        is_new
+                    });
+                }
+            }
+        }
+        let mut result = String::new();
+        write_canonical(&mut result, &value);
+        result
+    }
+
+    /// Compute a 64-bit hash of the [canonical form](TileJSON::to_canonical_string) of this document.
+    ///
+    /// The hash uses the FNV-1a algorithm, so it is stable across processes, platforms,
+    /// and Rust versions, but it is not cryptographically secure.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        self.to_canonical_string()
+            .bytes()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Compute a strong HTTP `ETag` header value from the [content hash](TileJSON::content_hash),
+    /// including the surrounding double quotes.
+    ///
+    /// Documents that only differ in key order, float formatting, or duplicate endpoints
+    /// produce the same `ETag`.
+    ///
+    /// ```
+    /// # use tilejson::tilejson;
+    /// let a = tilejson! { "https://a/".to_string(), minzoom: 0, maxzoom: 10 };
+    /// let b: tilejson::TileJSON = serde_json::from_str(
+    ///     r#"{"maxzoom": 10, "tiles": ["https://a/", "https://a/"], "minzoom": 0, "tilejson": "3.0.0"}"#
+    /// ).unwrap();
+    /// assert_eq!(a.etag(), b.etag());
+    /// assert_eq!(a.etag().len(), 18);
+    /// ```
+    #[must_use]
+    pub fn etag(&self) -> String {
+        format!("\"{:016x}\"", self.content_hash())
+    }
+}
+
+fn write_canonical(out: &mut String, value: &Value) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => out.push_str(&value.to_string()),
+        Value::Number(v) => write_number(out, v),
+        Value::Array(values) => {
+            out.push('[');
+            for (idx, v) in values.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_canonical(out, v);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (idx, (key, v)) in entries.into_iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(out, v);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_number(out: &mut String, value: &Number) {
+    // Integers up to 2^53 are exactly representable as f64
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+    match value.as_f64() {
+        #[allow(clippy::cast_possible_truncation)]
+        Some(v) if value.is_f64() && v.fract() == 0.0 && v.abs() <= MAX_SAFE_INTEGER => {
+            let _ = write!(out, "{}", v as i64);
+        }
+        Some(v) if value.is_f64() => {
+            let _ = write!(out, "{v}");
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::tilejson;
+
+    #[test]
+    fn test_canonical() {
+        let mut tj = tilejson! {
+            tiles: vec!["https://b/".to_string(), "https://a/".to_string(), "https://b/".to_string()],
+            grids: vec!["https://g/".to_string(), "https://g/".to_string()],
+        };
+        tj.other.insert(
+            "ext".to_string(),
+            json!({"z": 1.0, "a": [0.1, -0.0, 1e21, 12_345_678_901_234_567_u64]}),
+        );
+        assert_eq!(
+            tj.to_canonical_string(),
+            r#"{"ext":{"a":[0.1,0,1000000000000000000000,12345678901234567],"z":1},"grids":["https://g/"],"tilejson":"3.0.0","tiles":["https://b/","https://a/"]}"#
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        let a = tilejson! { "https://a/".to_string(), name: "a".to_string() };
+        let b = tilejson! { "https://a/".to_string(), name: "b".to_string() };
+        assert_eq!(a.content_hash(), a.clone().content_hash());
+        assert_ne!(a.content_hash(), b.content_hash());
+        // The hash must never change for the same content
+        assert_eq!(a.etag(), "\"6d6dbaadb37a4998\"");
+    }
+}
@@ -1,15 +1,33 @@
 use std::fmt::{Display, Formatter, Write as _};
-use std::num::{ParseFloatError, ParseIntError};
+use std::num::ParseFloatError;
 use std::str::FromStr;
 
-use serde_tuple::{Deserialize_tuple, Serialize_tuple};
+use serde::de::{IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
-#[derive(Serialize_tuple, Deserialize_tuple, PartialEq, Debug, Default, Copy, Clone)]
+use crate::json_error::DomainError;
+use crate::number::LooseF64;
+
+/// The default location of the tileset, as `[longitude, latitude, zoom]`.
+///
+/// The zoom is optional and may be fractional. It is omitted if it is not set, and
+/// in human-readable formats it is serialized as an integer if it has no fractional part.
+///
+/// ```
+/// # use tilejson::Center;
+/// let center: Center = serde_json::from_str("[1.5, -2.5, 10.5]").unwrap();
+/// assert_eq!(center.zoom, Some(10.5));
+/// let center: Center = serde_json::from_str("[1.5, -2.5]").unwrap();
+/// assert_eq!(center.zoom, None);
+/// assert_eq!(serde_json::to_string(&Center::new(1.5, -2.5, 8)).unwrap(), "[1.5,-2.5,8]");
+/// ```
+#[derive(PartialEq, Debug, Default, Copy, Clone)]
 pub struct Center {
     pub longitude: f64,
     pub latitude: f64,
-    pub zoom: u8,
+    pub zoom: Option<f64>,
 }
 
 impl Center {
@@ -18,8 +36,95 @@ impl Center {
         Self {
             longitude,
             latitude,
-            zoom,
+            zoom: Some(f64::from(zoom)),
+        }
+    }
+
+    /// Create a center without a zoom level.
+    #[must_use]
+    pub fn without_zoom(longitude: f64, latitude: f64) -> Self {
+        Self {
+            longitude,
+            latitude,
+            zoom: None,
+        }
+    }
+
+    /// Get the zoom as an integer zoom level, rounding down any fractional part.
+    /// Returns `None` if the zoom is not set, or is not within the `u8` range.
+    ///
+    /// ```
+    /// # use tilejson::Center;
+    /// assert_eq!(Center::from((1.0, 2.0, 10.5)).zoom_level(), Some(10));
+    /// assert_eq!(Center::without_zoom(1.0, 2.0).zoom_level(), None);
+    /// ```
+    #[must_use]
+    pub fn zoom_level(&self) -> Option<u8> {
+        self.zoom.and_then(zoom_to_u8)
+    }
+}
+
+/// Convert a zoom to an integer, rounding down. Returns `None` if it is outside the `u8` range.
+fn zoom_to_u8(zoom: f64) -> Option<u8> {
+    if (0.0..256.0).contains(&zoom) {
+        // The value is within the u8 range, checked above
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(zoom.floor() as u8)
+    } else {
+        None
+    }
+}
+
+impl Serialize for Center {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Binary formats keep the zoom a float, and need the length as the zoom is optional
+        let human_readable = serializer.is_human_readable();
+        let mut seq = serializer.serialize_seq(Some(if self.zoom.is_some() { 3 } else { 2 }))?;
+        seq.serialize_element(&self.longitude)?;
+        seq.serialize_element(&self.latitude)?;
+        if let Some(zoom) = self.zoom {
+            match zoom_to_u8(zoom) {
+                Some(v) if human_readable && zoom.fract() == 0.0 => seq.serialize_element(&v)?,
+                _ => seq.serialize_element(&zoom)?,
+            }
         }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Center {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CenterVisitor;
+
+        impl<'de> Visitor<'de> for CenterVisitor {
+            type Value = Center;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("an array of longitude, latitude, and an optional zoom")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Center, A::Error> {
+                let longitude = seq
+                    .next_element::<LooseF64>()?
+                    .ok_or_else(|| DomainError::CenterLen.into_error::<A::Error>())?
+                    .0;
+                let latitude = seq
+                    .next_element::<LooseF64>()?
+                    .ok_or_else(|| DomainError::CenterLen.into_error::<A::Error>())?
+                    .0;
+                let zoom = seq.next_element::<LooseF64>()?.map(|v| v.0);
+                if seq.next_element::<IgnoredAny>()?.is_some() {
+                    return Err(DomainError::CenterLen.into_error());
+                }
+                Ok(Center {
+                    longitude,
+                    latitude,
+                    zoom,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(CenterVisitor)
     }
 }
 
@@ -34,26 +139,27 @@ impl Display for Center {
     /// assert_eq!(center.to_string(), "1.5,-2.5,8");
     /// assert_eq!(format!("{center:.2}"), "1.50,-2.50,8");
     /// assert_eq!(Center::from_str(&center.to_string()).unwrap(), center);
+    /// assert_eq!(Center::from((1.5, -2.5, 10.5)).to_string(), "1.5,-2.5,10.5");
+    /// assert_eq!(Center::without_zoom(1.5, -2.5).to_string(), "1.5,-2.5");
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         self.longitude.fmt(f)?;
         f.write_char(',')?;
         self.latitude.fmt(f)?;
-        f.write_char(',')?;
-        write!(f, "{}", self.zoom)
+        if let Some(zoom) = self.zoom {
+            write!(f, ",{zoom}")?;
+        }
+        Ok(())
     }
 }
 
 #[derive(Error, Debug, PartialEq, Eq, Clone)]
 pub enum ParseCenterError {
-    #[error("Incorrect number of values. Center expects two f64 and one u8 values.")]
+    #[error("Incorrect number of values. Center expects two or three f64 values.")]
     BadLen,
     /// Wrapped error from the `parse::<f64>()`
     #[error(transparent)]
     ParseCoordError(#[from] ParseFloatError),
-    /// Wrapped error from the `parse::<u8>()`
-    #[error(transparent)]
-    ParseZoomError(#[from] ParseIntError),
 }
 
 impl From<(f64, f64, u8)> for Center {
@@ -70,7 +176,7 @@ impl From<(f64, f64, u8)> for Center {
         Self {
             longitude: value.0,
             latitude: value.1,
-            zoom: value.2,
+            zoom: Some(f64::from(value.2)),
         }
     }
 }
@@ -89,16 +195,45 @@ impl From<(f32, f32, u8)> for Center {
         Self {
             longitude: f64::from(value.0),
             latitude: f64::from(value.1),
-            zoom: value.2,
+            zoom: Some(f64::from(value.2)),
         }
     }
 }
 
+impl From<(f64, f64, f64)> for Center {
+    /// Parse a tuple as a Center value with a fractional zoom.
+    ///
+    /// ```
+    /// # use tilejson::Center;
+    /// assert_eq!(Center::from((1.0, 2.0, 2.5)).zoom, Some(2.5));
+    /// ```
+    fn from(value: (f64, f64, f64)) -> Self {
+        Self {
+            longitude: value.0,
+            latitude: value.1,
+            zoom: Some(value.2),
+        }
+    }
+}
+
+impl From<(f64, f64)> for Center {
+    /// Parse a tuple as a Center value without a zoom.
+    ///
+    /// ```
+    /// # use tilejson::Center;
+    /// assert_eq!(Center::from((1.0, 2.0)), Center::without_zoom(1.0, 2.0));
+    /// ```
+    fn from(value: (f64, f64)) -> Self {
+        Self::without_zoom(value.0, value.1)
+    }
+}
+
 impl FromStr for Center {
     type Err = ParseCenterError;
 
-    /// Parse a string of four comma-separated values as a Center value,
-    /// same order as the [`Center::new`] constructor. Extra spaces are ignored.
+    /// Parse a string of two or three comma-separated values as a Center value,
+    /// same order as the [`Center::new`] constructor. The zoom is optional and may be fractional.
+    /// Extra spaces are ignored.
     ///
     /// # Example
     /// ```
@@ -106,13 +241,15 @@ impl FromStr for Center {
     /// # use std::str::FromStr;
     /// let center = Center::from_str("1.0, 2.0, 3").unwrap();
     /// assert_eq!(center, Center::new(1.0, 2.0, 3));
+    /// assert_eq!(Center::from_str("1.0, 2.0").unwrap(), Center::without_zoom(1.0, 2.0));
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut vals = s.split(',').map(str::trim);
         let mut next_val = || vals.next().ok_or(ParseCenterError::BadLen);
         let center = Self {
             longitude: next_val()?.parse()?,
             latitude: next_val()?.parse()?,
-            zoom: next_val()?.parse()?,
+            zoom: vals.next().map(str::parse).transpose()?,
         };
         match vals.next() {
             Some(_) => Err(ParseCenterError::BadLen),
@@ -128,17 +265,16 @@ mod tests {
     #[test]
     fn test_parse_err() {
         const E_EMPTY: &str = "cannot parse float from empty string";
-        const E_FORMAT: &str = "invalid digit found in string";
-        const E_LEN: &str = "Incorrect number of values. Center expects two f64 and one u8 values.";
+        const E_FORMAT: &str = "invalid float literal";
+        const E_LEN: &str = "Incorrect number of values. Center expects two or three f64 values.";
 
         let err_to_str = |s| Center::from_str(s).unwrap_err().to_string();
 
         assert_eq!(err_to_str(""), E_EMPTY);
         assert_eq!(err_to_str("1"), E_LEN);
-        assert_eq!(err_to_str("1,2"), E_LEN);
         assert_eq!(err_to_str("1,2,3,4"), E_LEN);
         assert_eq!(err_to_str("1,2,a"), E_FORMAT);
-        assert_eq!(err_to_str("1,2,1.1"), E_FORMAT);
+        assert_eq!(err_to_str("1,2,"), E_EMPTY);
         assert_eq!(err_to_str("1,,0"), E_EMPTY);
     }
 
@@ -147,5 +283,22 @@ mod tests {
         let val = |s| Center::from_str(s).unwrap();
         assert_eq!(val("0,0,0"), Center::new(0.0, 0.0, 0));
         assert_eq!(val("  1 ,2.0, 3 "), Center::new(1.0, 2.0, 3));
+        assert_eq!(val("1,2,1.5"), Center::from((1.0, 2.0, 1.5)));
+        assert_eq!(val("1,2"), Center::without_zoom(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_serde() {
+        let parse = |s| serde_json::from_str::<Center>(s);
+        let to_json = |c: Center| serde_json::to_string(&c).unwrap();
+        assert_eq!(to_json(parse("[1, 2, 3]").unwrap()), "[1.0,2.0,3]");
+        assert_eq!(to_json(parse("[1, 2, 3.0]").unwrap()), "[1.0,2.0,3]");
+        assert_eq!(to_json(parse("[1, 2, 2.5]").unwrap()), "[1.0,2.0,2.5]");
+        assert_eq!(to_json(parse("[1, 2]").unwrap()), "[1.0,2.0]");
+        assert_eq!(to_json(Center::from((1., 2., -1.))), "[1.0,2.0,-1.0]");
+        parse("[]").unwrap_err();
+        parse("[1]").unwrap_err();
+        parse("[1, 2, 3, 4]").unwrap_err();
+        parse(r#"[1, 2, "3"]"#).unwrap_err();
     }
 }
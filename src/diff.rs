@@ -0,0 +1,337 @@
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::TileJSON;
+
+/// Array values whose elements are matched by a key instead of their position
+const KEYED_ARRAYS: &[(&str, &str)] = &[("vector_layers", "id"), ("tilestats.layers", "layer")];
+
+/// A single difference between two `TileJSON` documents, as reported by [`TileJSON::diff`].
+///
+/// The `path` is a dot-separated list of keys leading to the changed value,
+/// e.g. `vector_layers[roads].fields.name`. Vector layers are identified by their id,
+/// and `tilestats` layers by their layer name, because they are matched by that key
+/// between the two documents, and their positions may differ.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Change {
+    /// The value is only present in the new document.
+    Added { path: String, value: Value },
+    /// The value is only present in the old document.
+    Removed { path: String, value: Value },
+    /// The value is present in both documents, but is different.
+    Changed {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl Change {
+    /// The path to the changed value.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            }
+        }
+    }
+}
+
+impl Display for Change {
+    /// Format the change as a single line, prefixed with `+`, `-`, or `~`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added { path, value } => write!(f, "+ {path}: {value}"),
+            Self::Removed { path, value } => write!(f, "- {path}: {value}"),
+            Self::Changed { path, old, new } => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+/// The differences between two `TileJSON` documents, as reported by [`TileJSON::diff`].
+///
+/// Serializes as a JSON array of changes, and displays as one change per line.
+#[derive(Serialize, Debug, PartialEq, Clone, Default)]
+#[serde(transparent)]
+pub struct TileJSONDiff {
+    pub changes: Vec<Change>,
+}
+
+impl TileJSONDiff {
+    /// Returns `true` if the documents are the same.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if any value was removed, which is usually a breaking change for the clients.
+    #[must_use]
+    pub fn has_removals(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|v| matches!(v, Change::Removed { .. }))
+    }
+}
+
+impl Display for TileJSONDiff {
+    /// Format all changes, one per line.
+    ///
+    /// ```
+    /// # use tilejson::tilejson;
+    /// let old = tilejson! { "https://example.com/".to_string(), minzoom: 0, name: "a".to_string() };
+    /// let new = tilejson! { "https://example.com/".to_string(), minzoom: 2, maxzoom: 10 };
+    /// assert_eq!(old.diff(&new).to_string(), "+ maxzoom: 10\n~ minzoom: 0 -> 2\n- name: \"a\"\n");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TileJSON {
+    /// Default tolerance used by [`TileJSON::diff`] when comparing floating point values.
+    pub const DIFF_TOLERANCE: f64 = 1e-9;
+
+    /// Compute a semantic diff between this (old) and another (new) document.
+    /// See [`TileJSON::diff_with_tolerance`] for details.
+    #[must_use]
+    pub fn diff(&self, other: &TileJSON) -> TileJSONDiff {
+        self.diff_with_tolerance(other, Self::DIFF_TOLERANCE)
+    }
+
+    /// Compute a semantic diff between this (old) and another (new) document.
+    ///
+    /// All keys are compared, including the ones in `other`, and the changes are reported in
+    /// the order of the keys. Vector layers are matched by their id, so that reordering them
+    /// is not reported as a change, and the added and removed layer fields are listed individually.
+    /// Numbers are considered equal if they differ by no more than `tolerance`.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Bounds, Change, VectorLayer};
+    /// let layer = |fields: &[&str]| VectorLayer::new(
+    ///     "roads".to_string(),
+    ///     fields.iter().map(|v| (v.to_string(), "String".to_string())).collect(),
+    /// );
+    /// let old = tilejson! {
+    ///     "https://example.com/".to_string(),
+    ///     bounds: Bounds::new(-10., -10., 10., 10.),
+    ///     vector_layers: vec![layer(&["name", "ref"])],
+    /// };
+    /// let new = tilejson! {
+    ///     "https://example.com/".to_string(),
+    ///     bounds: Bounds::new(-10., -10., 10., 10.000_000_000_1),
+    ///     vector_layers: vec![layer(&["name"])],
+    /// };
+    /// let diff = old.diff(&new);
+    /// assert!(diff.has_removals());
+    /// assert_eq!(diff.to_string(), "- vector_layers[roads].fields.ref: \"String\"\n");
+    /// ```
+    #[must_use]
+    pub fn diff_with_tolerance(&self, other: &TileJSON, tolerance: f64) -> TileJSONDiff {
+        let mut changes = Vec::new();
+        let old = self.to_json_value();
+        let new = other.to_json_value();
+        diff_values("", &old, &new, tolerance, &mut changes);
+        TileJSONDiff { changes }
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, tolerance: f64, changes: &mut Vec<Change>) {
+    let keyed = KEYED_ARRAYS.iter().find(|(p, _)| *p == path);
+    match (old, new, keyed) {
+        (Value::Object(old), Value::Object(new), _) => {
+            diff_maps(path, old, new, tolerance, changes);
+        }
+        (Value::Array(old), Value::Array(new), Some((_, key))) => {
+            diff_keyed_arrays(path, key, old, new, tolerance, changes);
+        }
+        _ => {
+            if !values_equal(old, new, tolerance) {
+                changes.push(Change::Changed {
+                    path: path.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn diff_maps(
+    path: &str,
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    tolerance: f64,
+    changes: &mut Vec<Change>,
+) {
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let key_path = join_path(path, key);
+        match (old.get(key), new.get(key)) {
+            (Some(old), Some(new)) => diff_values(&key_path, old, new, tolerance, changes),
+            (Some(old), None) => changes.push(Change::Removed {
+                path: key_path,
+                value: old.clone(),
+            }),
+            (None, Some(new)) => changes.push(Change::Added {
+                path: key_path,
+                value: new.clone(),
+            }),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Compare arrays of objects by matching the elements with the same `key` value
+fn diff_keyed_arrays(
+    path: &str,
+    key: &str,
+    old: &[Value],
+    new: &[Value],
+    tolerance: f64,
+    changes: &mut Vec<Change>,
+) {
+    let get_id = |v: &Value| {
+        v.get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    let find = |items: &[Value], id: &str| items.iter().find(|v| get_id(v) == id).cloned();
+    for old_item in old {
+        let id = get_id(old_item);
+        let path = format!("{path}[{id}]");
+        match find(new, &id) {
+            Some(new_item) => diff_values(&path, old_item, &new_item, tolerance, changes),
+            None => changes.push(Change::Removed {
+                path,
+                value: old_item.clone(),
+            }),
+        }
+    }
+    for new_item in new {
+        let id = get_id(new_item);
+        if find(old, &id).is_none() {
+            changes.push(Change::Added {
+                path: format!("{path}[{id}]"),
+                value: new_item.clone(),
+            });
+        }
+    }
+}
+
+/// Compare two values, treating numbers as equal if they differ by no more than `tolerance`
+pub(crate) fn values_equal(old: &Value, new: &Value, tolerance: f64) -> bool {
+    match (old, new) {
+        (Value::Number(a), Value::Number(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => (a - b).abs() <= tolerance,
+            _ => a == b,
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_equal(a, b, tolerance))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, v)| b.get(k).is_some_and(|b| values_equal(v, b, tolerance)))
+        }
+        _ => old == new,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{tilejson, Center, VectorLayer};
+
+    fn layer(id: &str, fields: &[&str]) -> VectorLayer {
+        VectorLayer::new(
+            id.to_string(),
+            fields
+                .iter()
+                .map(|v| ((*v).to_string(), "String".to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let tj = tilejson! {
+            "https://example.com/".to_string(),
+            center: Center::new(1.0, 2.0, 3),
+            vector_layers: vec![layer("a", &["x"]), layer("b", &["y"])],
+        };
+        let mut reordered = tj.clone();
+        reordered.vector_layers.as_mut().unwrap().reverse();
+        reordered.center = Some(Center::new(1.000_000_000_01, 2.0, 3));
+        assert!(tj.diff(&reordered).is_empty());
+        assert!(!tj.diff_with_tolerance(&reordered, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_changes() {
+        let mut old = tilejson! {
+            "https://example.com/".to_string(),
+            minzoom: 0,
+            vector_layers: vec![layer("a", &["x", "y"]), layer("b", &["y"])],
+        };
+        old.other.insert("foo".to_string(), json!(1));
+        let mut new = tilejson! {
+            "https://example.com/v2".to_string(),
+            minzoom: 0,
+            vector_layers: vec![layer("a", &["x", "z"]), layer("c", &[])],
+        };
+        new.other.insert("foo".to_string(), json!("1"));
+
+        let diff = old.diff(&new);
+        assert!(diff.has_removals());
+        let paths: Vec<_> = diff.changes.iter().map(Change::path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "foo",
+                "tiles",
+                "vector_layers[a].fields.y",
+                "vector_layers[a].fields.z",
+                "vector_layers[b]",
+                "vector_layers[c]",
+            ]
+        );
+        assert_eq!(
+            diff.changes[0],
+            Change::Changed {
+                path: "foo".to_string(),
+                old: json!(1),
+                new: json!("1"),
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&diff).unwrap()[2],
+            json!({"op": "removed", "path": "vector_layers[a].fields.y", "value": "String"})
+        );
+        assert_eq!(
+            diff.to_string().lines().nth(1),
+            Some(r#"~ tiles: ["https://example.com/"] -> ["https://example.com/v2"]"#)
+        );
+    }
+}
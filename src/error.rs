@@ -0,0 +1,134 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::bounds::ParseBoundsError;
+use crate::camera::ParseCameraError;
+use crate::center::ParseCenterError;
+use crate::extension::ExtensionError;
+use crate::json_error::JsonError;
+use crate::merge::MergeError;
+use crate::patch::PatchError;
+use crate::strict::StrictError;
+use crate::structure::StructureError;
+use crate::subset::SubsetError;
+use crate::tile_size::TileSizeError;
+use crate::validate::ValidationError;
+use crate::zoom::ZoomError;
+use crate::TileJSON;
+
+/// Any error produced by this crate. Each of the more specific errors converts into it,
+/// so functions returning different error types can be combined with the `?` operator.
+///
+/// ```
+/// # use tilejson::{Bounds, Center, TileJSON};
+/// fn parse(bounds: &str, center: &str) -> tilejson::Result<TileJSON> {
+///     let mut tj = TileJSON::from_json_str(r#"{"tilejson": "3.0.0", "tiles": ["x"]}"#)?;
+///     tj.bounds = Some(bounds.parse::<Bounds>()?);
+///     tj.center = Some(center.parse::<Center>()?);
+///     tj.validate()?;
+///     Ok(tj)
+/// }
+///
+/// assert!(parse("-10,-10,10,10", "0,0,5").is_ok());
+/// assert!(matches!(parse("-10,-10,10", "0,0,5"), Err(tilejson::Error::ParseBounds(_))));
+/// assert!(matches!(parse("-10,-10,10,10", "50,0,5"), Err(tilejson::Error::Validation(_))));
+/// ```
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    ParseBounds(#[from] ParseBoundsError),
+    #[error(transparent)]
+    ParseCenter(#[from] ParseCenterError),
+    #[error(transparent)]
+    ParseCamera(#[from] ParseCameraError),
+    #[error(transparent)]
+    TileSize(#[from] TileSizeError),
+    #[error(transparent)]
+    Zoom(#[from] ZoomError),
+    #[error(transparent)]
+    Json(#[from] JsonError),
+    #[error(transparent)]
+    Strict(#[from] StrictError),
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+    #[error(transparent)]
+    Structure(#[from] StructureError),
+    #[error(transparent)]
+    Merge(#[from] MergeError),
+    #[error(transparent)]
+    Subset(#[from] SubsetError),
+    #[error(transparent)]
+    Extension(#[from] ExtensionError),
+    #[error(transparent)]
+    Patch(#[from] PatchError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A `Result` alias with [`enum@Error`] as the default error type.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl TileJSON {
+    /// Read a `TileJSON` document from a file.
+    ///
+    /// # Errors
+    /// Returns [`Error::Io`] if the file cannot be opened,
+    /// and [`Error::Json`] if its content is not a valid `TileJSON` document.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<TileJSON> {
+        let file = File::open(path)?;
+        Ok(Self::from_reader(BufReader::new(file))?)
+    }
+
+    /// Write this document as JSON into a file, replacing its content.
+    ///
+    /// # Errors
+    /// Returns [`Error::Io`] if the file cannot be created or written.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.to_writer(File::create(path)?)
+    }
+
+    /// Write this document as JSON into a writer.
+    ///
+    /// # Errors
+    /// Returns [`Error::Io`] if the writer fails.
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = BufWriter::new(writer);
+        serde_json::to_writer(&mut writer, self).map_err(std::io::Error::from)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tilejson;
+
+    #[test]
+    fn test_file_round_trip() {
+        let tj = tilejson! { "https://example.com/".to_string(), minzoom: 2 };
+        let path = std::env::temp_dir().join(format!("tilejson-{}.json", std::process::id()));
+        tj.to_file(&path).unwrap();
+        let result = TileJSON::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), tj);
+
+        assert!(matches!(TileJSON::from_file(&path), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let err: Error = "1".parse::<crate::Center>().unwrap_err().into();
+        assert!(matches!(err, Error::ParseCenter(ParseCenterError::BadLen)));
+        assert_eq!(
+            err.to_string(),
+            "Incorrect number of values. Center expects two or three f64 values."
+        );
+
+        let err: Error = TileJSON::from_json_str("[]").unwrap_err().into();
+        assert!(matches!(err, Error::Json(_)));
+    }
+}
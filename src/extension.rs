@@ -0,0 +1,456 @@
+use std::any::type_name;
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::json_error::from_str;
+use crate::{StrictError, TileJSON, VectorLayer};
+
+/// A typed view of one or more vendor-specific keys stored in [`TileJSON::other`]
+/// or [`VectorLayer::other`].
+///
+/// Implementors declare the keys they own, and are (de)serialized from a JSON object
+/// containing only those keys. Use `#[serde(skip_serializing_if = "Option::is_none")]`
+/// on optional fields, so that unset values are removed from `other`.
+/// With the `derive` feature, `#[derive(TileJsonExtension)]` implements this trait
+/// together with the serde glue, including key prefixes and validation.
+///
+/// The widely used `tileSize`, `format`, `encoding` and `tilestats` keys are not extensions,
+/// but typed fields of [`TileJSON`], because this crate relies on them itself, e.g. to validate
+/// the tile size and to merge or subset the layer statistics. They are never stored in `other`.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use tilejson::{tilejson, Extension};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Generator {
+///     generator: String,
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     generator_options: Option<String>,
+/// }
+///
+/// impl Extension for Generator {
+///     const KEYS: &'static [&'static str] = &["generator", "generator_options"];
+/// }
+///
+/// let mut tj = tilejson! { "https://example.com/".to_string() };
+/// assert_eq!(tj.ext::<Generator>().unwrap(), None);
+///
+/// let generator = Generator { generator: "tippecanoe v2.0".to_string(), generator_options: None };
+/// tj.set_ext(&generator).unwrap();
+/// assert_eq!(tj.other["generator"], "tippecanoe v2.0");
+/// assert_eq!(tj.ext::<Generator>().unwrap(), Some(generator));
+/// ```
+pub trait Extension: Serialize + DeserializeOwned {
+    /// All keys of `other` owned by this extension.
+    const KEYS: &'static [&'static str];
+
+    /// Check the values beyond what is enforced by deserialization.
+    ///
+    /// # Errors
+    /// Returns a message describing the problem if the values are not valid.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Parse the extension from the owned keys of the map.
+    /// Returns `None` if none of the keys are present.
+    ///
+    /// # Errors
+    /// Returns [`ExtensionError::Deserialize`] if the values do not match this type.
+    fn from_other(other: &BTreeMap<String, Value>) -> Result<Option<Self>, ExtensionError> {
+        let values: Map<String, Value> = Self::KEYS
+            .iter()
+            .filter_map(|&key| Some((key.to_string(), other.get(key)?.clone())))
+            .collect();
+        if values.is_empty() {
+            return Ok(None);
+        }
+        serde_json::from_value(Value::Object(values))
+            .map(Some)
+            .map_err(|source| ExtensionError::Deserialize {
+                name: type_name::<Self>(),
+                source,
+            })
+    }
+
+    /// Store the extension in the owned keys of the map, removing owned keys
+    /// that are not present in the serialized value. Other keys are not modified.
+    ///
+    /// # Errors
+    /// Returns an error if the extension does not serialize to an object,
+    /// or if the object contains a key that is not in [`Extension::KEYS`].
+    fn to_other(&self, other: &mut BTreeMap<String, Value>) -> Result<(), ExtensionError> {
+        let name = type_name::<Self>();
+        let value = serde_json::to_value(self)
+            .map_err(|source| ExtensionError::Serialize { name, source })?;
+        let Value::Object(values) = value else {
+            return Err(ExtensionError::NotAnObject { name });
+        };
+        if let Some(key) = values.keys().find(|v| !Self::KEYS.contains(&v.as_str())) {
+            return Err(ExtensionError::UndeclaredKey {
+                name,
+                key: key.clone(),
+            });
+        }
+        for key in Self::KEYS {
+            other.remove(*key);
+        }
+        other.extend(values);
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ExtensionError {
+    #[error("Unable to parse extension {name}: {source}")]
+    Deserialize {
+        name: &'static str,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Unable to serialize extension {name}: {source}")]
+    Serialize {
+        name: &'static str,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Extension {name} must serialize to a JSON object")]
+    NotAnObject { name: &'static str },
+    #[error("Extension {name} serialized key {key:?}, which is not listed in its KEYS")]
+    UndeclaredKey { name: &'static str, key: String },
+    #[error("Invalid extension {name}: {message}")]
+    Invalid { name: &'static str, message: String },
+}
+
+impl TileJSON {
+    /// Parse the extension from [`TileJSON::other`], or `None` if none of its keys are set.
+    ///
+    /// # Errors
+    /// Returns an error if the values do not match the extension type.
+    pub fn ext<E: Extension>(&self) -> Result<Option<E>, ExtensionError> {
+        E::from_other(&self.other)
+    }
+
+    /// Store the extension in [`TileJSON::other`], replacing any previous values of its keys.
+    ///
+    /// # Errors
+    /// Returns an error if the extension cannot be serialized to an object.
+    pub fn set_ext<E: Extension>(&mut self, ext: &E) -> Result<(), ExtensionError> {
+        ext.to_other(&mut self.other)
+    }
+
+    /// Remove all keys of the extension from [`TileJSON::other`].
+    pub fn remove_ext<E: Extension>(&mut self) {
+        for key in E::KEYS {
+            self.other.remove(*key);
+        }
+    }
+}
+
+impl VectorLayer {
+    /// Parse the extension from [`VectorLayer::other`], or `None` if none of its keys are set.
+    ///
+    /// # Errors
+    /// Returns an error if the values do not match the extension type.
+    pub fn ext<E: Extension>(&self) -> Result<Option<E>, ExtensionError> {
+        E::from_other(&self.other)
+    }
+
+    /// Store the extension in [`VectorLayer::other`], replacing any previous values of its keys.
+    ///
+    /// # Errors
+    /// Returns an error if the extension cannot be serialized to an object.
+    pub fn set_ext<E: Extension>(&mut self, ext: &E) -> Result<(), ExtensionError> {
+        ext.to_other(&mut self.other)
+    }
+
+    /// Remove all keys of the extension from [`VectorLayer::other`].
+    pub fn remove_ext<E: Extension>(&mut self) {
+        for key in E::KEYS {
+            self.other.remove(*key);
+        }
+    }
+}
+
+type CheckFn = fn(&BTreeMap<String, Value>) -> Result<(), ExtensionError>;
+
+/// A set of known extensions, used to validate the `other` values of a document,
+/// and to accept their keys when parsing it with [`ExtensionRegistry::parse_strict`].
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use tilejson::{Extension, ExtensionRegistry};
+/// #[derive(Serialize, Deserialize)]
+/// struct MaskLevel {
+///     #[serde(rename = "maskLevel")]
+///     mask_level: u8,
+/// }
+///
+/// impl Extension for MaskLevel {
+///     const KEYS: &'static [&'static str] = &["maskLevel"];
+/// }
+///
+/// let registry = ExtensionRegistry::new().with::<MaskLevel>();
+/// let json = r#"{"tilejson": "3.0.0", "tiles": ["x"], "maskLevel": 8}"#;
+/// let tj = registry.parse_strict(json).unwrap();
+/// assert!(registry.validate(&tj).is_ok());
+///
+/// let json = r#"{"tilejson": "3.0.0", "tiles": ["x"], "maskLevel": "8"}"#;
+/// let tj = registry.parse_strict(json).unwrap();
+/// assert!(registry.validate(&tj).is_err());
+///
+/// // The extension was registered for the document, not for the vector layers
+/// let json = r#"{"tilejson": "3.0.0", "tiles": ["x"],
+///     "vector_layers": [{"id": "a", "fields": {}, "maskLevel": 8}]}"#;
+/// assert!(registry.parse_strict(json).is_err());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ExtensionRegistry {
+    tilejson: Vec<(&'static [&'static str], CheckFn)>,
+    layers: Vec<(&'static [&'static str], CheckFn)>,
+}
+
+impl ExtensionRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an extension of the `TileJSON` document.
+    pub fn register<E: Extension>(&mut self) -> &mut Self {
+        self.tilejson.push((E::KEYS, check::<E>));
+        self
+    }
+
+    /// Register an extension of the vector layers.
+    pub fn register_layer<E: Extension>(&mut self) -> &mut Self {
+        self.layers.push((E::KEYS, check::<E>));
+        self
+    }
+
+    /// Same as [`ExtensionRegistry::register`], but consumes and returns the registry.
+    #[must_use]
+    pub fn with<E: Extension>(mut self) -> Self {
+        self.register::<E>();
+        self
+    }
+
+    /// Same as [`ExtensionRegistry::register_layer`], but consumes and returns the registry.
+    #[must_use]
+    pub fn with_layer<E: Extension>(mut self) -> Self {
+        self.register_layer::<E>();
+        self
+    }
+
+    /// Get the keys of the extensions registered for the document, in registration order.
+    #[must_use]
+    pub fn keys(&self) -> Vec<&'static str> {
+        all_keys(&self.tilejson)
+    }
+
+    /// Get the keys of the extensions registered for the vector layers, in registration order.
+    #[must_use]
+    pub fn layer_keys(&self) -> Vec<&'static str> {
+        all_keys(&self.layers)
+    }
+
+    /// Parse a JSON string as `TileJSON`, rejecting any unknown keys except the keys
+    /// of the extensions registered for the document or for the vector layers respectively.
+    /// See [`TileJSON::from_value_strict`] for details.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid `TileJSON` document,
+    /// or if it contains a key that is not allowed.
+    pub fn parse_strict(&self, s: &str) -> Result<TileJSON, StrictError> {
+        TileJSON::from_value_strict_with(from_str(s)?, &self.keys(), &self.layer_keys())
+    }
+
+    /// Parse and validate all registered extensions that are present in the document
+    /// and in its vector layers.
+    ///
+    /// # Errors
+    /// Returns the first error of an extension that cannot be parsed or is not valid.
+    pub fn validate(&self, tilejson: &TileJSON) -> Result<(), ExtensionError> {
+        for (_, check) in &self.tilejson {
+            check(&tilejson.other)?;
+        }
+        for layer in tilejson.vector_layers.iter().flatten() {
+            for (_, check) in &self.layers {
+                check(&layer.other)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn all_keys(extensions: &[(&'static [&'static str], CheckFn)]) -> Vec<&'static str> {
+    extensions
+        .iter()
+        .flat_map(|(keys, _)| keys.iter().copied())
+        .collect()
+}
+
+fn check<E: Extension>(other: &BTreeMap<String, Value>) -> Result<(), ExtensionError> {
+    match E::from_other(other)? {
+        Some(ext) => ext.validate().map_err(|message| ExtensionError::Invalid {
+            name: type_name::<E>(),
+            message,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Support code for the `TileJsonExtension` derive macro, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::Value;
+
+    pub type Map = serde_json::Map<String, Value>;
+
+    /// Insert the serialized value, unless it is `null`
+    pub fn insert<T: Serialize>(
+        map: &mut Map,
+        key: &str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        let value = serde_json::to_value(value)?;
+        if !value.is_null() {
+            map.insert(key.to_string(), value);
+        }
+        Ok(())
+    }
+
+    /// Remove and parse the value of the key, using the default or `null` if it is missing
+    pub fn take<T: DeserializeOwned, E: serde::de::Error>(
+        map: &mut Map,
+        key: &'static str,
+        default: Option<fn() -> T>,
+    ) -> Result<T, E> {
+        match (map.remove(key), default) {
+            (Some(value), _) => {
+                serde_json::from_value(value).map_err(|e| E::custom(format!("{key}: {e}")))
+            }
+            (None, Some(default)) => Ok(default()),
+            (None, None) => serde_json::from_value(Value::Null).map_err(|_| E::missing_field(key)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::tilejson;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Generator {
+        generator: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        generator_options: Option<String>,
+    }
+
+    impl Extension for Generator {
+        const KEYS: &'static [&'static str] = &["generator", "generator_options"];
+
+        fn validate(&self) -> Result<(), String> {
+            if self.generator.is_empty() {
+                Err("generator must not be empty".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Color {
+        color: String,
+    }
+
+    impl Extension for Color {
+        const KEYS: &'static [&'static str] = &["color"];
+    }
+
+    #[test]
+    fn test_ext() {
+        let mut tj = tilejson! { "https://example.com/".to_string() };
+        tj.other.insert("foo".to_string(), json!(1));
+        tj.other.insert("generator".to_string(), json!("a"));
+        tj.other
+            .insert("generator_options".to_string(), json!("-z14"));
+
+        let generator = tj.ext::<Generator>().unwrap().unwrap();
+        assert_eq!(generator.generator_options.as_deref(), Some("-z14"));
+
+        tj.set_ext(&Generator {
+            generator: "b".to_string(),
+            generator_options: None,
+        })
+        .unwrap();
+        assert_eq!(json!(tj.other), json!({"foo": 1, "generator": "b"}));
+
+        tj.remove_ext::<Generator>();
+        assert_eq!(json!(tj.other), json!({"foo": 1}));
+
+        tj.other.insert("generator".to_string(), json!(5));
+        assert!(matches!(
+            tj.ext::<Generator>(),
+            Err(ExtensionError::Deserialize { .. })
+        ));
+    }
+
+    #[test]
+    fn test_undeclared_key() {
+        #[derive(Serialize, Deserialize)]
+        struct Bad {
+            a: u8,
+        }
+        impl Extension for Bad {
+            const KEYS: &'static [&'static str] = &["b"];
+        }
+
+        let mut tj = tilejson! { "https://example.com/".to_string() };
+        let err = tj.set_ext(&Bad { a: 1 }).unwrap_err();
+        assert!(matches!(err, ExtensionError::UndeclaredKey { ref key, .. } if key == "a"));
+        assert!(tj.other.is_empty());
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = ExtensionRegistry::new()
+            .with::<Generator>()
+            .with_layer::<Color>();
+        assert_eq!(registry.keys(), vec!["generator", "generator_options"]);
+        assert_eq!(registry.layer_keys(), vec!["color"]);
+
+        let mut layer = VectorLayer::new("roads".to_string(), BTreeMap::new());
+        layer
+            .set_ext(&Color {
+                color: "red".to_string(),
+            })
+            .unwrap();
+        let mut tj = tilejson! {
+            "https://example.com/".to_string(),
+            vector_layers: vec![layer],
+        };
+        assert!(registry.validate(&tj).is_ok());
+
+        tj.other.insert("generator".to_string(), json!(""));
+        let err = registry.validate(&tj).unwrap_err();
+        assert!(err.to_string().ends_with(": generator must not be empty"));
+
+        tj.other.clear();
+        tj.vector_layers.as_mut().unwrap()[0]
+            .other
+            .insert("color".to_string(), json!(1));
+        assert!(registry.validate(&tj).is_err());
+    }
+}
@@ -0,0 +1,296 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::TileJSON;
+
+/// Encoding of the tile data, as stored in the `format` extension.
+///
+/// Unrecognized values are kept as [`TileFormat::Other`] and written back unchanged.
+/// Known names are matched ignoring case, and aliases like `jpeg` and `geojson` are read as
+/// the canonical format, so they are written back as `jpg` and `json`.
+///
+/// ```
+/// # use tilejson::TileFormat;
+/// assert_eq!("jpeg".parse::<TileFormat>().unwrap(), TileFormat::Jpg);
+/// assert_eq!(TileFormat::Webp.to_string(), "webp");
+/// assert_eq!(TileFormat::from_url("https://example.com/{z}/{x}/{y}.pbf?key=1"), Some(TileFormat::Pbf));
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum TileFormat {
+    Png,
+    Jpg,
+    Webp,
+    Avif,
+    /// Mapbox Vector Tile, usually gzip-compressed
+    Pbf,
+    /// Mapbox Vector Tile, same as [`TileFormat::Pbf`] but with a different file extension
+    Mvt,
+    /// `MapLibre` Tile
+    Mlt,
+    Json,
+    /// Any other or unrecognized value
+    Other(String),
+}
+
+impl TileFormat {
+    /// Get the format for a file extension, ignoring case. Returns `None` for unknown extensions.
+    #[must_use]
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpg),
+            "webp" => Some(Self::Webp),
+            "avif" => Some(Self::Avif),
+            "pbf" => Some(Self::Pbf),
+            "mvt" => Some(Self::Mvt),
+            "mlt" => Some(Self::Mlt),
+            "json" | "geojson" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Infer the format from the file extension of a tile URL template,
+    /// ignoring any query string or fragment.
+    #[must_use]
+    pub fn from_url(url: &str) -> Option<Self> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let (name, ext) = path.rsplit_once('/')?.1.rsplit_once('.')?;
+        if name.is_empty() {
+            return None;
+        }
+        Self::from_extension(ext)
+    }
+
+    /// Get the format name, as written to the `format` field.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Png => "png",
+            Self::Jpg => "jpg",
+            Self::Webp => "webp",
+            Self::Avif => "avif",
+            Self::Pbf => "pbf",
+            Self::Mvt => "mvt",
+            Self::Mlt => "mlt",
+            Self::Json => "json",
+            Self::Other(v) => v,
+        }
+    }
+
+    /// Check if the tiles contain vector data, rather than images.
+    #[must_use]
+    pub fn is_vector(&self) -> bool {
+        matches!(self, Self::Pbf | Self::Mvt | Self::Mlt | Self::Json)
+    }
+
+    /// Check if the tiles are raster images.
+    #[must_use]
+    pub fn is_raster(&self) -> bool {
+        matches!(self, Self::Png | Self::Jpg | Self::Webp | Self::Avif)
+    }
+}
+
+impl From<String> for TileFormat {
+    fn from(value: String) -> Self {
+        Self::from_extension(&value).unwrap_or(Self::Other(value))
+    }
+}
+
+impl From<TileFormat> for String {
+    fn from(value: TileFormat) -> Self {
+        match value {
+            TileFormat::Other(v) => v,
+            v => v.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for TileFormat {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s.to_string()))
+    }
+}
+
+impl Display for TileFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Encoding of the elevation in the pixels of `raster-dem` tiles, as stored in the `encoding` extension.
+///
+/// Unrecognized values are kept as [`RasterDemEncoding::Other`], so that they survive a round trip.
+/// See the [`MapLibre` style spec](https://maplibre.org/maplibre-style-spec/sources/#encoding).
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Default)]
+#[serde(from = "String", into = "String")]
+pub enum RasterDemEncoding {
+    /// Mapbox Terrain RGB: `-10000 + (R * 256 * 256 + G * 256 + B) * 0.1`
+    #[default]
+    Mapbox,
+    /// Terrarium: `R * 256 + G + B / 256 - 32768`
+    Terrarium,
+    /// Custom factors and offset, defined by the map style
+    Custom,
+    /// Any other or unrecognized value
+    Other(String),
+}
+
+impl RasterDemEncoding {
+    /// Get the encoding name, as written to the `encoding` field.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mapbox => "mapbox",
+            Self::Terrarium => "terrarium",
+            Self::Custom => "custom",
+            Self::Other(v) => v,
+        }
+    }
+
+    /// Decode the elevation in meters from the red, green, and blue values of a pixel.
+    /// Returns `None` for the [`Custom`](RasterDemEncoding::Custom) encoding,
+    /// because its factors are not stored in the `TileJSON` document, and for unknown encodings.
+    ///
+    /// ```
+    /// # use tilejson::RasterDemEncoding;
+    /// assert_eq!(RasterDemEncoding::Mapbox.elevation(1, 134, 160), Some(0.0));
+    /// assert_eq!(RasterDemEncoding::Terrarium.elevation(128, 0, 0), Some(0.0));
+    /// ```
+    #[must_use]
+    pub fn elevation(&self, red: u8, green: u8, blue: u8) -> Option<f64> {
+        let (r, g, b) = (f64::from(red), f64::from(green), f64::from(blue));
+        match self {
+            Self::Mapbox => Some(-10000.0 + (r * 65536.0 + g * 256.0 + b) * 0.1),
+            Self::Terrarium => Some(r * 256.0 + g + b / 256.0 - 32768.0),
+            Self::Custom | Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<String> for RasterDemEncoding {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "mapbox" => Self::Mapbox,
+            "terrarium" => Self::Terrarium,
+            "custom" => Self::Custom,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<RasterDemEncoding> for String {
+    fn from(value: RasterDemEncoding) -> Self {
+        match value {
+            RasterDemEncoding::Other(v) => v,
+            v => v.as_str().to_string(),
+        }
+    }
+}
+
+impl Display for RasterDemEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TileJSON {
+    /// Get the tile format, either from the `format` field,
+    /// or inferred from the file extension of the first tile URL that has a known one.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, TileFormat};
+    /// let tj = tilejson! { "https://example.com/{z}/{x}/{y}.webp".to_string() };
+    /// assert_eq!(tj.format, None);
+    /// assert_eq!(tj.tile_format(), Some(TileFormat::Webp));
+    /// ```
+    #[must_use]
+    pub fn tile_format(&self) -> Option<TileFormat> {
+        self.format
+            .clone()
+            .or_else(|| self.tiles.iter().find_map(|v| TileFormat::from_url(v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tilejson;
+
+    #[test]
+    fn test_from_url() {
+        let fmt = TileFormat::from_url;
+        assert_eq!(
+            fmt("https://example.com/{z}/{x}/{y}.PNG"),
+            Some(TileFormat::Png)
+        );
+        assert_eq!(fmt("/tiles/{z}/{x}/{y}.jpeg#v2"), Some(TileFormat::Jpg));
+        assert_eq!(
+            fmt("https://example.com/{z}/{x}/{y}.mlt"),
+            Some(TileFormat::Mlt)
+        );
+        assert_eq!(fmt("https://example.com/{z}/{x}/{y}"), None);
+        assert_eq!(fmt("https://example.com/v1.2/{z}/{x}/{y}"), None);
+        assert_eq!(fmt("https://example.com/tiles/.png"), None);
+        assert_eq!(fmt("https://example.com/{z}/{x}/{y}.tif"), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let tj: TileJSON = serde_json::from_str(
+            r#"{"tilejson":"3.0.0","tiles":["x.png"],"format":"jpeg","encoding":"terrarium"}"#,
+        )
+        .unwrap();
+        assert_eq!(tj.format, Some(TileFormat::Jpg));
+        assert_eq!(tj.encoding, Some(RasterDemEncoding::Terrarium));
+        assert_eq!(tj.tile_format(), Some(TileFormat::Jpg));
+        assert!(tj.other.is_empty());
+        assert_eq!(
+            serde_json::to_string(&tj).unwrap(),
+            r#"{"tilejson":"3.0.0","tiles":["x.png"],"format":"jpg","encoding":"terrarium"}"#
+        );
+
+        let tj = tilejson! {
+            "https://example.com/a".to_string(),
+            format: TileFormat::from("geotiff".to_string()),
+        };
+        assert_eq!(tj.format, Some(TileFormat::Other("geotiff".to_string())));
+        assert!(serde_json::to_string(&tj)
+            .unwrap()
+            .contains(r#""format":"geotiff""#));
+
+        let tj: TileJSON =
+            serde_json::from_str(r#"{"tilejson":"3.0.0","tiles":[],"encoding":"lerc"}"#).unwrap();
+        assert_eq!(
+            tj.encoding,
+            Some(RasterDemEncoding::Other("lerc".to_string()))
+        );
+        assert_eq!(
+            serde_json::to_string(&tj).unwrap(),
+            r#"{"tilejson":"3.0.0","tiles":[],"encoding":"lerc"}"#
+        );
+    }
+
+    #[test]
+    fn test_elevation() {
+        assert_eq!(RasterDemEncoding::Mapbox.elevation(0, 0, 0), Some(-10000.0));
+        assert_eq!(
+            RasterDemEncoding::Terrarium.elevation(0, 0, 0),
+            Some(-32768.0)
+        );
+        assert_eq!(
+            RasterDemEncoding::Terrarium.elevation(129, 2, 128),
+            Some(258.5)
+        );
+        assert_eq!(RasterDemEncoding::Custom.elevation(1, 2, 3), None);
+        assert_eq!(
+            RasterDemEncoding::Other("lerc".to_string()).elevation(1, 2, 3),
+            None
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use std::io::Read;
+
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::bounds::ParseBoundsError;
+use crate::center::ParseCenterError;
+use crate::TileJSON;
+
+/// The reason a JSON document could not be parsed, see [`JsonError`].
+#[derive(Error, Debug)]
+pub enum JsonErrorKind {
+    #[error("bounds must have 4 elements: left, bottom, right, top")]
+    Bounds(#[source] ParseBoundsError),
+    #[error("center must have 2 or 3 elements: lon, lat, and an optional zoom")]
+    Center(#[source] ParseCenterError),
+    /// The input is not syntactically valid JSON.
+    #[error("{0}")]
+    Syntax(String),
+    /// The input is valid JSON, but does not match the `TileJSON` structure.
+    #[error("{0}")]
+    Data(String),
+    /// The input ended in the middle of a JSON value.
+    #[error("{0}")]
+    Eof(String),
+    /// The underlying reader failed.
+    #[error("{0}")]
+    Io(#[source] std::io::Error),
+}
+
+/// A JSON parsing error with the location of the offending value,
+/// as returned by [`TileJSON::from_json_str`] and [`TileJSON::from_reader`].
+///
+/// ```
+/// # use tilejson::TileJSON;
+/// let err = TileJSON::from_json_str(
+///     r#"{"tilejson": "3.0.0", "tiles": ["x"], "center": [1]}"#
+/// ).unwrap_err();
+/// assert_eq!(err.path(), "center");
+/// assert_eq!((err.line(), err.column()), (1, 51));
+/// assert_eq!(
+///     err.to_string(),
+///     "center: center must have 2 or 3 elements: lon, lat, and an optional zoom at line 1 column 51"
+/// );
+/// ```
+#[derive(Error, Debug)]
+#[error("{}{kind} at line {line} column {column}", path_prefix(path))]
+pub struct JsonError {
+    path: String,
+    line: usize,
+    column: usize,
+    #[source]
+    kind: JsonErrorKind,
+}
+
+impl JsonError {
+    fn new(path: String, err: serde_json::Error) -> Self {
+        let (line, column) = (err.line(), err.column());
+        let message = || {
+            let message = err.to_string();
+            let suffix = format!(" at line {line} column {column}");
+            message
+                .strip_suffix(&suffix)
+                .unwrap_or(&message)
+                .to_string()
+        };
+        let kind = match err.classify() {
+            Category::Io => JsonErrorKind::Io(err.into()),
+            Category::Syntax => JsonErrorKind::Syntax(message()),
+            Category::Eof => JsonErrorKind::Eof(message()),
+            Category::Data => {
+                let message = message();
+                match DomainError::from_message(&message) {
+                    Some(err) => err.kind(),
+                    None => JsonErrorKind::Data(message),
+                }
+            }
+        };
+        Self {
+            path,
+            line,
+            column,
+            kind,
+        }
+    }
+
+    /// Path to the offending value, e.g. `vector_layers[3].minzoom`,
+    /// or an empty string if the error is not related to a specific value.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// One-based line number of the error, or 0 if it is unknown.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// One-based column number of the error, or 0 if it is unknown.
+    #[must_use]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The reason of the error.
+    #[must_use]
+    pub fn kind(&self) -> &JsonErrorKind {
+        &self.kind
+    }
+}
+
+/// An error of the `Bounds` and `Center` deserializers, reported as a specific [`JsonErrorKind`].
+///
+/// Serde only passes custom errors as messages, so the error is written as a message
+/// starting with a fixed code, and recognized by that code in [`DomainError::from_message`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum DomainError {
+    BoundsLen,
+    CenterLen,
+}
+
+impl DomainError {
+    const ALL: [Self; 2] = [Self::BoundsLen, Self::CenterLen];
+
+    fn code(self) -> &'static str {
+        match self {
+            Self::BoundsLen => "E_BOUNDS_LEN",
+            Self::CenterLen => "E_CENTER_LEN",
+        }
+    }
+
+    fn kind(self) -> JsonErrorKind {
+        match self {
+            Self::BoundsLen => JsonErrorKind::Bounds(ParseBoundsError::BadLen),
+            Self::CenterLen => JsonErrorKind::Center(ParseCenterError::BadLen),
+        }
+    }
+
+    /// Convert into a deserialization error of any format
+    pub(crate) fn into_error<E: serde::de::Error>(self) -> E {
+        E::custom(format_args!("{}: {}", self.code(), self.kind()))
+    }
+
+    fn from_message(message: &str) -> Option<Self> {
+        let (code, _) = message.split_once(": ")?;
+        Self::ALL.into_iter().find(|v| v.code() == code)
+    }
+}
+
+fn path_prefix(path: &str) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!("{path}: ")
+    }
+}
+
+impl TileJSON {
+    /// Parse a JSON string as `TileJSON`. Unlike `serde_json::from_str`,
+    /// the error includes the path to the offending value.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid `TileJSON` document.
+    pub fn from_json_str(s: &str) -> Result<TileJSON, JsonError> {
+        parse(serde_json::Deserializer::from_str(s))
+    }
+
+    /// Parse `TileJSON` from a reader, e.g. a file. Unlike `serde_json::from_reader`,
+    /// the error includes the path to the offending value.
+    ///
+    /// # Errors
+    /// Returns an error if the reader fails, or if its content is not a valid `TileJSON` document.
+    pub fn from_reader<R: Read>(reader: R) -> Result<TileJSON, JsonError> {
+        parse(serde_json::Deserializer::from_reader(reader))
+    }
+}
+
+impl From<serde_json::Error> for JsonError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::new(String::new(), err)
+    }
+}
+
+/// Parse a JSON string, reporting the path to the offending value on error
+pub(crate) fn from_str<T: DeserializeOwned>(s: &str) -> Result<T, JsonError> {
+    parse(serde_json::Deserializer::from_str(s))
+}
+
+/// Parse a JSON value, reporting the path to the offending value on error
+pub(crate) fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, JsonError> {
+    serde_path_to_error::deserialize(value).map_err(path_error)
+}
+
+fn parse<'de, R, T>(mut deserializer: serde_json::Deserializer<R>) -> Result<T, JsonError>
+where
+    R: serde_json::de::Read<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(path_error)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+fn path_error(err: serde_path_to_error::Error<serde_json::Error>) -> JsonError {
+    let path = err.path().to_string();
+    let path = if path == "." { String::new() } else { path };
+    JsonError::new(path, err.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "gone"))
+        }
+    }
+
+    fn err(json: &str) -> JsonError {
+        TileJSON::from_json_str(json).unwrap_err()
+    }
+
+    #[test]
+    fn test_domain_errors() {
+        for center in ["[]", "[1]", "[1,2,3,4]"] {
+            let err = err(&format!(
+                r#"{{"tilejson":"3.0.0","tiles":["x"],"center":{center}}}"#
+            ));
+            assert!(matches!(
+                err.kind(),
+                JsonErrorKind::Center(ParseCenterError::BadLen)
+            ));
+        }
+        for bounds in ["[]", "[1,2,3]", "[1,2,3,4,5]"] {
+            let err = err(&format!(
+                r#"{{"tilejson":"3.0.0","tiles":["x"],"bounds":{bounds}}}"#
+            ));
+            assert!(matches!(
+                err.kind(),
+                JsonErrorKind::Bounds(ParseBoundsError::BadLen)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_nested_domain_errors() {
+        #[derive(serde::Deserialize, Debug)]
+        struct Area {
+            #[allow(dead_code)]
+            bounds: crate::Bounds,
+        }
+
+        let err =
+            from_str::<Vec<Area>>(r#"[{"bounds": [1, 2, 3, 4]}, {"bounds": [1, 2]}]"#).unwrap_err();
+        assert_eq!(err.path(), "[1].bounds");
+        assert!(matches!(
+            err.kind(),
+            JsonErrorKind::Bounds(ParseBoundsError::BadLen)
+        ));
+
+        let err = from_str::<Vec<crate::Center>>("[[1, 2], [1, 2, 3, 4]]").unwrap_err();
+        assert_eq!(err.path(), "[1]");
+        assert!(matches!(
+            err.kind(),
+            JsonErrorKind::Center(ParseCenterError::BadLen)
+        ));
+
+        // Other formats see the code and the description of the error
+        let err = serde_json::from_str::<crate::Bounds>("[1]").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("E_BOUNDS_LEN: bounds must have 4 elements"));
+    }
+
+    #[test]
+    fn test_paths() {
+        let json = r#"{
+  "tilejson": "3.0.0",
+  "tiles": ["x"],
+  "vector_layers": [
+    {"id": "a", "fields": {}},
+    {"id": "b", "fields": {}, "minzoom": -1}
+  ]
+}"#;
+        let err = err(json);
+        assert_eq!(err.path(), "vector_layers[1].minzoom");
+        assert_eq!((err.line(), err.column()), (6, 43));
+        assert_eq!(
+            err.to_string(),
+            "vector_layers[1].minzoom: invalid value: integer `-1`, expected u8 at line 6 column 43"
+        );
+
+        let err = TileJSON::from_reader(r#"{"tilejson":"3.0.0","tiles":["x"]} x"#.as_bytes())
+            .unwrap_err();
+        assert_eq!(err.path(), "");
+        assert!(matches!(err.kind(), JsonErrorKind::Syntax(_)));
+        assert_eq!(err.to_string(), "trailing characters at line 1 column 36");
+    }
+
+    #[test]
+    fn test_categories() {
+        let eof = err(r#"{"tilejson":"3.0.0","tiles":"#);
+        assert!(matches!(eof.kind(), JsonErrorKind::Eof(_)));
+        let data = err(r#"{"tilejson":"3.0.0","tiles":["x"],"center":"x"}"#);
+        assert_eq!(data.path(), "center");
+        assert!(matches!(data.kind(), JsonErrorKind::Data(_)));
+
+        let err = TileJSON::from_reader(FailingReader).unwrap_err();
+        let JsonErrorKind::Io(io) = err.kind() else {
+            panic!("expected an I/O error, got {err:?}");
+        };
+        assert_eq!(io.kind(), std::io::ErrorKind::BrokenPipe);
+    }
+}
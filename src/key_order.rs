@@ -0,0 +1,503 @@
+use std::ops::Range;
+
+use serde::de::IgnoredAny;
+use serde_json::{Map, Value};
+
+use crate::json_error::{from_str, JsonError};
+use crate::TileJSON;
+
+/// The key order and layout of an original `TileJSON` document, used to write an edited
+/// document back with a minimal textual diff. Requires the `preserve_order` feature.
+///
+/// Values that were not changed are copied from the original text as is, keeping their
+/// whitespace and number formatting, e.g. `-180` is not rewritten as `-180.0`.
+/// Keys present in the original are written in their original order, including the keys
+/// of nested objects like the vector layer `fields` or any unknown keys in `other`, and
+/// new keys are appended after them. Changed objects and arrays keep their original
+/// indentation and line breaks, and new values follow the indentation of the document.
+/// Vector layers and other arrays of objects with an `id` key are matched by id,
+/// and other array elements by position.
+///
+/// ```
+/// # use tilejson::TileJSON;
+/// let json = r#"{"tiles": ["https://example.com/"], "bounds": [-180, -85, 180, 85], "tilejson": "3.0.0"}"#;
+/// let (mut tj, order) = TileJSON::from_str_ordered(json).unwrap();
+/// tj.name = Some("b".to_string());
+/// assert_eq!(
+///     order.to_json_string(&tj).unwrap(),
+///     r#"{"tiles": ["https://example.com/"], "bounds": [-180, -85, 180, 85], "tilejson": "3.0.0", "name": "b"}"#
+/// );
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct KeyOrder {
+    /// The original document
+    text: String,
+    root: Node,
+    /// One level of indentation used by the original document
+    indent: String,
+}
+
+impl KeyOrder {
+    /// Record the key order and layout of a JSON string.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid JSON.
+    pub fn from_json_str(s: &str) -> Result<Self, JsonError> {
+        from_str::<IgnoredAny>(s)?;
+        let mut scanner = Scanner { text: s, pos: 0 };
+        scanner.whitespace();
+        let root = scanner.node();
+        let indent = match &root.kind {
+            NodeKind::Object(_, Some(layout)) | NodeKind::Array(_, Some(layout))
+                if layout.is_multiline() =>
+            {
+                line_indent(&layout.open).to_string()
+            }
+            _ => String::new(),
+        };
+        Ok(Self {
+            text: s.to_string(),
+            root,
+            indent: if indent.is_empty() {
+                "  ".to_string()
+            } else {
+                indent
+            },
+        })
+    }
+
+    /// Serialize the document with the recorded key order and layout.
+    ///
+    /// # Errors
+    /// Returns an error if the document cannot be converted to JSON.
+    pub fn to_json_string(&self, tilejson: &TileJSON) -> Result<String, JsonError> {
+        let value = serde_json::to_value(tilejson)?;
+        let mut writer = Writer {
+            text: &self.text,
+            pretty: self.text[self.root.span.clone()].contains('\n'),
+            indent: &self.indent,
+            out: self.text[..self.root.span.start].to_string(),
+        };
+        writer.value(&value, Some(&self.root), "");
+        writer.out.push_str(&self.text[self.root.span.end..]);
+        Ok(writer.out)
+    }
+}
+
+impl TileJSON {
+    /// Parse a JSON string as `TileJSON`, and record its key order and layout,
+    /// so that [`KeyOrder::to_json_string`] can write the edited document back
+    /// with a minimal textual diff.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid `TileJSON` document.
+    pub fn from_str_ordered(s: &str) -> Result<(TileJSON, KeyOrder), JsonError> {
+        let order = KeyOrder::from_json_str(s)?;
+        Ok((from_str(s)?, order))
+    }
+}
+
+/// A value of the original document
+#[derive(PartialEq, Debug, Clone)]
+struct Node {
+    /// Location of the value in the original text
+    span: Range<usize>,
+    kind: NodeKind,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum NodeKind {
+    Scalar,
+    /// Array elements, and their layout unless the array is empty
+    Array(Vec<Node>, Option<Layout>),
+    /// Object keys with their location in the text, values,
+    /// and the layout unless the object is empty
+    Object(Vec<(String, Range<usize>, Node)>, Option<Layout>),
+}
+
+/// The whitespace around the elements of an array or an object
+#[derive(PartialEq, Debug, Clone, Default)]
+struct Layout {
+    /// Before the first element
+    open: String,
+    /// After each comma, unknown if there is only one element
+    separator: Option<String>,
+    /// After the last element
+    close: String,
+    /// Between an object key and its value, including the colon
+    colon: String,
+}
+
+impl Layout {
+    fn is_multiline(&self) -> bool {
+        self.open.contains('\n')
+    }
+}
+
+/// The indentation of the last line of the whitespace
+fn line_indent(whitespace: &str) -> &str {
+    whitespace.rsplit('\n').next().unwrap_or_default()
+}
+
+/// Finds the location of all values in a valid JSON text
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> u8 {
+        self.text
+            .as_bytes()
+            .get(self.pos)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn whitespace(&mut self) -> String {
+        let start = self.pos;
+        while matches!(self.peek(), b' ' | b'\t' | b'\n' | b'\r') {
+            self.pos += 1;
+        }
+        self.text[start..self.pos].to_string()
+    }
+
+    fn node(&mut self) -> Node {
+        let start = self.pos;
+        let kind = match self.peek() {
+            b'[' => {
+                let (items, layout) = self.elements(b']', Scanner::node);
+                NodeKind::Array(items, layout)
+            }
+            b'{' => {
+                let (entries, layout) = self.elements(b'}', |s| {
+                    let key_start = s.pos;
+                    s.string();
+                    let key_span = key_start..s.pos;
+                    let key = serde_json::from_str(&s.text[key_span.clone()]).unwrap_or_default();
+                    (key, key_span, s.node_after_colon())
+                });
+                let layout = layout.map(|mut layout| {
+                    layout.colon = entries
+                        .first()
+                        .map(|(_, key, node)| self.text[key.end..node.span.start].to_string())
+                        .unwrap_or_default();
+                    layout
+                });
+                NodeKind::Object(entries, layout)
+            }
+            b'"' => {
+                self.string();
+                NodeKind::Scalar
+            }
+            _ => {
+                while !matches!(
+                    self.peek(),
+                    b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r' | 0
+                ) {
+                    self.pos += 1;
+                }
+                NodeKind::Scalar
+            }
+        };
+        Node {
+            span: start..self.pos,
+            kind,
+        }
+    }
+
+    fn node_after_colon(&mut self) -> Node {
+        self.whitespace();
+        self.pos += 1;
+        self.whitespace();
+        self.node()
+    }
+
+    /// Scan the elements of an array or an object, starting at the opening bracket
+    fn elements<T>(
+        &mut self,
+        close: u8,
+        mut element: impl FnMut(&mut Self) -> T,
+    ) -> (Vec<T>, Option<Layout>) {
+        self.pos += 1;
+        let open = self.whitespace();
+        let mut items = Vec::new();
+        if self.peek() == close {
+            self.pos += 1;
+            return (items, None);
+        }
+        let mut layout = Layout {
+            open,
+            ..Layout::default()
+        };
+        loop {
+            items.push(element(self));
+            let whitespace = self.whitespace();
+            let next = self.peek();
+            self.pos += 1;
+            if next == close {
+                layout.close = whitespace;
+                return (items, Some(layout));
+            }
+            let separator = self.whitespace();
+            layout.separator.get_or_insert(separator);
+        }
+    }
+
+    fn string(&mut self) {
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return;
+                }
+                0 => return,
+                _ => self.pos += 1,
+            }
+        }
+    }
+}
+
+impl Node {
+    /// Check if the value is the same as the original one, comparing numbers by their value
+    fn is_same(&self, text: &str, value: &Value) -> bool {
+        match (&self.kind, value) {
+            (NodeKind::Scalar, _) => {
+                match serde_json::from_str::<Value>(&text[self.span.clone()]) {
+                    Ok(Value::Number(a)) => match value {
+                        Value::Number(b) => a == *b || a.as_f64() == b.as_f64(),
+                        _ => false,
+                    },
+                    Ok(v) => v == *value,
+                    Err(_) => false,
+                }
+            }
+            (NodeKind::Array(items, _), Value::Array(values)) => {
+                items.len() == values.len()
+                    && items.iter().zip(values).all(|(n, v)| n.is_same(text, v))
+            }
+            (NodeKind::Object(entries, _), Value::Object(map)) => {
+                entries.len() == map.len()
+                    && entries
+                        .iter()
+                        .all(|(k, _, n)| map.get(k).is_some_and(|v| n.is_same(text, v)))
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the original value of the `id` key of an object
+    fn id(&self, text: &str) -> Option<Value> {
+        let NodeKind::Object(entries, _) = &self.kind else {
+            return None;
+        };
+        let (_, _, node) = entries.iter().find(|(k, _, _)| k == "id")?;
+        serde_json::from_str(&text[node.span.clone()]).ok()
+    }
+}
+
+/// Writes a value, reusing the original text and layout wherever possible
+struct Writer<'a> {
+    text: &'a str,
+    /// Write new objects and arrays on multiple lines
+    pretty: bool,
+    indent: &'a str,
+    out: String,
+}
+
+impl Writer<'_> {
+    /// Write a value that starts on a line with the given indentation
+    fn value(&mut self, value: &Value, node: Option<&Node>, line: &str) {
+        if let Some(node) = node.filter(|n| n.is_same(self.text, value)) {
+            self.out.push_str(&self.text[node.span.clone()]);
+            return;
+        }
+        let kind = node.map(|n| &n.kind);
+        match value {
+            Value::Object(map) => {
+                let (entries, layout) = match kind {
+                    Some(NodeKind::Object(entries, layout)) => {
+                        (entries.as_slice(), layout.as_ref())
+                    }
+                    _ => (&[][..], None),
+                };
+                self.object(map, entries, layout, line);
+            }
+            Value::Array(values) => {
+                let (items, layout) = match kind {
+                    Some(NodeKind::Array(items, layout)) => (items.as_slice(), layout.as_ref()),
+                    _ => (&[][..], None),
+                };
+                let text = self.text;
+                let elements: Vec<_> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, v)| {
+                        let node = match v.get("id") {
+                            Some(id) => items.iter().find(|n| n.id(text).as_ref() == Some(id)),
+                            None => items.get(idx),
+                        };
+                        (None, v, node)
+                    })
+                    .collect();
+                self.elements(('[', ']'), &elements, layout, line);
+            }
+            _ => self.out.push_str(&value.to_string()),
+        }
+    }
+
+    fn object(
+        &mut self,
+        map: &Map<String, Value>,
+        entries: &[(String, Range<usize>, Node)],
+        layout: Option<&Layout>,
+        line: &str,
+    ) {
+        let text = self.text;
+        let mut elements: Vec<_> = entries
+            .iter()
+            .filter_map(|(key, span, node)| {
+                let value = map.get(key)?;
+                Some((Some(text[span.clone()].to_string()), value, Some(node)))
+            })
+            .collect();
+        for (key, value) in map {
+            if !entries.iter().any(|(k, _, _)| k == key) {
+                elements.push((Some(Value::from(key.as_str()).to_string()), value, None));
+            }
+        }
+        self.elements(('{', '}'), &elements, layout, line);
+    }
+
+    /// Write the elements of an array or an object, with optional object keys
+    fn elements(
+        &mut self,
+        (open, close): (char, char),
+        elements: &[(Option<String>, &Value, Option<&Node>)],
+        layout: Option<&Layout>,
+        line: &str,
+    ) {
+        self.out.push(open);
+        if elements.is_empty() {
+            self.out.push(close);
+            return;
+        }
+        let layout = layout.cloned().unwrap_or_else(|| self.new_layout(line));
+        let separator = layout.separator.clone().unwrap_or_else(|| {
+            if layout.is_multiline() {
+                layout.open.clone()
+            } else if self.pretty {
+                " ".to_string()
+            } else {
+                String::new()
+            }
+        });
+        let inner = if layout.is_multiline() {
+            line_indent(&layout.open)
+        } else {
+            line
+        };
+        for (idx, (key, value, node)) in elements.iter().enumerate() {
+            if idx == 0 {
+                self.out.push_str(&layout.open);
+            } else {
+                self.out.push(',');
+                self.out.push_str(&separator);
+            }
+            if let Some(key) = key {
+                self.out.push_str(key);
+                self.out.push_str(&layout.colon);
+            }
+            self.value(value, *node, inner);
+        }
+        self.out.push_str(&layout.close);
+        self.out.push(close);
+    }
+
+    /// The layout of a new, or originally empty, array or object
+    fn new_layout(&self, line: &str) -> Layout {
+        if self.pretty {
+            let open = format!("\n{line}{}", self.indent);
+            Layout {
+                separator: Some(open.clone()),
+                open,
+                close: format!("\n{line}"),
+                colon: ": ".to_string(),
+            }
+        } else {
+            Layout {
+                colon: ":".to_string(),
+                ..Layout::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Zoom;
+
+    #[test]
+    fn test_round_trip() {
+        let json = r#"{
+  "vector_layers": [
+    {
+      "id": "roads",
+      "fields": {
+        "name": "String",
+        "class": "String"
+      },
+      "x-custom": {
+        "z": 1,
+        "a": 2
+      }
+    }
+  ],
+  "tiles": [
+    "https://example.com/{z}/{x}/{y}.pbf"
+  ],
+  "tilejson": "3.0.0"
+}
+"#;
+        let (tj, order) = TileJSON::from_str_ordered(json).unwrap();
+        assert_eq!(order.to_json_string(&tj).unwrap(), json);
+    }
+
+    #[test]
+    fn test_edits() {
+        let json = r#"{"vector_layers":[{"id":"a","fields":{}},{"id":"b","fields":{"y":"","x":""}}],"tilejson":"3.0.0","tiles":[]}"#;
+        let (mut tj, order) = TileJSON::from_str_ordered(json).unwrap();
+        let layers = tj.vector_layers.as_mut().unwrap();
+        layers.remove(0);
+        layers[0].description = Some("B".to_string());
+        layers[0].fields.insert("w".to_string(), String::new());
+        assert_eq!(
+            order.to_json_string(&tj).unwrap(),
+            r#"{"vector_layers":[{"id":"b","fields":{"y":"","x":"","w":""},"description":"B"}],"tilejson":"3.0.0","tiles":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        let json =
+            "{\n    \"tilejson\": \"3.0.0\",\n    \"tiles\": [\"https://example.com/\"],\n    \
+            \"bounds\": [-180, -85.0511, 180, 85.0511],\n    \"center\": [0, 0, 2],\n    \
+            \"vector_layers\": [{\"id\": \"a\", \"fields\": {}}]\n}";
+        let (mut tj, order) = TileJSON::from_str_ordered(json).unwrap();
+        tj.minzoom = Some(Zoom::new(2).unwrap());
+        tj.center.as_mut().unwrap().zoom = Some(3.0);
+        tj.vector_layers.as_mut().unwrap()[0]
+            .fields
+            .insert("name".to_string(), "String".to_string());
+        assert_eq!(
+            order.to_json_string(&tj).unwrap(),
+            "{\n    \"tilejson\": \"3.0.0\",\n    \"tiles\": [\"https://example.com/\"],\n    \
+            \"bounds\": [-180, -85.0511, 180, 85.0511],\n    \"center\": [0, 0, 3],\n    \
+            \"vector_layers\": [{\"id\": \"a\", \"fields\": {\n        \"name\": \"String\"\n    }}],\n    \
+            \"minzoom\": 2\n}"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::bounds::Bounds;
+use crate::center::Center;
+use crate::json_error::{from_str, from_value, JsonError};
+use crate::TileJSON;
+
+/// A value that was coerced by the lenient parser, see [`TileJSON::from_str_lenient`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LenientWarning {
+    /// Path to the coerced value, e.g. `bounds` or `vector_layers[2].maxzoom`.
+    pub path: String,
+    /// Description of the coercion.
+    pub message: String,
+}
+
+impl Display for LenientWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl TileJSON {
+    /// Parse a JSON string as `TileJSON`, accepting common deviations from the spec.
+    /// See [`TileJSON::from_value_lenient`] for the list of accepted deviations.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid JSON, or if the document
+    /// cannot be parsed as `TileJSON` even after the coercions.
+    ///
+    /// ```
+    /// # use tilejson::{Bounds, TileJSON};
+    /// let (tj, warnings) = TileJSON::from_str_lenient(r#"{
+    ///     "tilejson": "3.0.0",
+    ///     "tiles": "https://example.com/{z}/{x}/{y}.png",
+    ///     "bounds": "-10,-20,10,20",
+    ///     "maxzoom": 14.0
+    /// }"#).unwrap();
+    /// assert_eq!(tj.tiles, vec!["https://example.com/{z}/{x}/{y}.png"]);
+    /// assert_eq!(tj.bounds, Some(Bounds::new(-10., -20., 10., 20.)));
+    /// assert_eq!(tj.maxzoom.unwrap(), 14);
+    /// assert_eq!(warnings.len(), 3);
+    /// assert_eq!(warnings[0].to_string(), "tiles: single string converted to an array");
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<(TileJSON, Vec<LenientWarning>), JsonError> {
+        Self::from_value_lenient(from_str(s)?)
+    }
+
+    /// Parse a JSON value as `TileJSON`, accepting common deviations from the spec
+    /// produced by some servers. Each coercion is reported as a [`LenientWarning`].
+    ///
+    /// * `tiles`, `grids`, and `data` given as a single string instead of an array.
+    /// * `bounds` given as a comma-separated string, as stored in `MBTiles` metadata.
+    /// * `center` given as a comma-separated string, or with the zoom given as a string.
+    /// * Zoom levels given as floating point numbers or as strings. Fractional values are rounded.
+    ///
+    /// # Errors
+    /// Returns an error if the document cannot be parsed as `TileJSON` even after the coercions.
+    pub fn from_value_lenient(
+        mut value: Value,
+    ) -> Result<(TileJSON, Vec<LenientWarning>), JsonError> {
+        let mut warnings = Vec::new();
+        if let Value::Object(map) = &mut value {
+            coerce_tilejson(map, &mut warnings);
+        }
+        Ok((from_value(value)?, warnings))
+    }
+}
+
+fn coerce_tilejson(map: &mut Map<String, Value>, warnings: &mut Vec<LenientWarning>) {
+    let mut warn = |path: &str, message: String| {
+        warnings.push(LenientWarning {
+            path: path.to_string(),
+            message,
+        });
+    };
+
+    for key in ["tiles", "grids", "data"] {
+        if let Some(value) = map.get_mut(key) {
+            if value.is_string() {
+                *value = Value::Array(vec![value.take()]);
+                warn(key, "single string converted to an array".to_string());
+            }
+        }
+    }
+
+    for key in ["minzoom", "maxzoom", "fillzoom"] {
+        if let Some(msg) = map.get_mut(key).and_then(coerce_zoom) {
+            warn(key, msg);
+        }
+    }
+
+    if let Some(value) = map.get_mut("bounds") {
+        if let Some(bounds) = value.as_str().and_then(|v| Bounds::from_str(v).ok()) {
+            *value = Value::from(vec![bounds.left, bounds.bottom, bounds.right, bounds.top]);
+            warn(
+                "bounds",
+                "comma-separated string converted to an array".to_string(),
+            );
+        }
+    }
+
+    if let Some(value) = map.get_mut("center") {
+        let center = value.as_str().and_then(|v| Center::from_str(v).ok());
+        if let Some(center) = center.and_then(|v| serde_json::to_value(v).ok()) {
+            *value = center;
+            warn(
+                "center",
+                "comma-separated string converted to an array".to_string(),
+            );
+        } else if let Some(zoom) = value.get_mut(2) {
+            // Center zoom may be fractional, so it is not rounded
+            if let Some(v) = zoom.as_str().and_then(|v| v.trim().parse::<f64>().ok()) {
+                warn("center", format!("zoom {zoom} converted to number {v}"));
+                *zoom = Value::from(v);
+            }
+        }
+    }
+
+    if let Some(Value::Array(layers)) = map.get_mut("vector_layers") {
+        for (idx, layer) in layers.iter_mut().enumerate() {
+            for key in ["minzoom", "maxzoom"] {
+                if let Some(msg) = layer.get_mut(key).and_then(coerce_zoom) {
+                    warn(&format!("vector_layers[{idx}].{key}"), msg);
+                }
+            }
+        }
+    }
+}
+
+/// Convert a zoom given as a float or a string into an integer, returning a warning message
+fn coerce_zoom(value: &mut Value) -> Option<String> {
+    if value.is_u64() {
+        return None;
+    }
+    let zoom = match value {
+        Value::Number(v) => v.as_f64()?,
+        Value::String(v) => v.trim().parse::<f64>().ok()?,
+        _ => return None,
+    };
+    if !(0.0..=f64::from(u8::MAX)).contains(&zoom) {
+        return None;
+    }
+    let msg = format!("zoom {value} converted to integer {}", zoom.round());
+    // The value is within the u8 range, checked above
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    {
+        *value = Value::from(zoom.round() as u8);
+    }
+    Some(msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn lenient(value: Value) -> (TileJSON, Vec<String>) {
+        let (tj, warnings) = TileJSON::from_value_lenient(value).unwrap();
+        (tj, warnings.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_valid_document() {
+        let (tj, warnings) = lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/"],
+            "center": [1, 2, 3],
+            "minzoom": 0,
+        }));
+        assert!(warnings.is_empty());
+        assert_eq!(tj.center, Some(Center::new(1., 2., 3)));
+    }
+
+    #[test]
+    fn test_coercions() {
+        let (tj, warnings) = lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/"],
+            "grids": "https://example.com/grid",
+            "center": "1.5, 2.5",
+            "minzoom": "2",
+            "maxzoom": 14.4,
+            "vector_layers": [
+                {"id": "a", "fields": {}, "minzoom": 3.0},
+                {"id": "b", "fields": {}, "maxzoom": "10"},
+            ],
+        }));
+        assert_eq!(
+            warnings,
+            vec![
+                "grids: single string converted to an array",
+                "minzoom: zoom \"2\" converted to integer 2",
+                "maxzoom: zoom 14.4 converted to integer 14",
+                "center: comma-separated string converted to an array",
+                "vector_layers[0].minzoom: zoom 3.0 converted to integer 3",
+                "vector_layers[1].maxzoom: zoom \"10\" converted to integer 10",
+            ]
+        );
+        assert_eq!(tj.grids, Some(vec!["https://example.com/grid".to_string()]));
+        assert_eq!(tj.center, Some(Center::without_zoom(1.5, 2.5)));
+        assert_eq!(tj.zoom_range().unwrap().to_string(), "2..=14");
+        let layers = tj.vector_layers.unwrap();
+        assert_eq!(layers[0].minzoom.map(u8::from), Some(3));
+        assert_eq!(layers[1].maxzoom.map(u8::from), Some(10));
+    }
+
+    #[test]
+    fn test_center() {
+        let (tj, warnings) = lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/"],
+            "center": [1, 2, 7.5],
+        }));
+        assert!(warnings.is_empty());
+        assert_eq!(tj.center, Some(Center::from((1., 2., 7.5))));
+
+        let (tj, warnings) = lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/"],
+            "center": [1, 2, "7.5"],
+        }));
+        assert_eq!(
+            warnings,
+            vec!["center: zoom \"7.5\" converted to number 7.5"]
+        );
+        assert_eq!(tj.center, Some(Center::from((1., 2., 7.5))));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err =
+            TileJSON::from_value_lenient(json!({"tilejson": "3.0.0", "tiles": 5})).unwrap_err();
+        assert_eq!(err.path(), "tiles");
+        TileJSON::from_value_lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["x"],
+            "bounds": "1,2,3",
+        }))
+        .unwrap_err();
+        TileJSON::from_value_lenient(json!({
+            "tilejson": "3.0.0",
+            "tiles": ["x"],
+            "maxzoom": -1.5,
+        }))
+        .unwrap_err();
+    }
+}
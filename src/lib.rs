@@ -7,15 +7,65 @@
 //! Use [`tilejson!`] macro to instantiate a valid [`TileJSON`].
 //! Use [`TileJSON::set_missing_defaults`] to populate default values per spec.
 
+mod borrowed;
 mod bounds;
+mod camera;
+mod canonical;
 mod center;
+mod diff;
+mod error;
+mod extension;
+mod format;
+mod json_error;
+#[cfg(feature = "preserve_order")]
+mod key_order;
+mod lenient;
+mod merge;
+mod number;
+#[cfg(feature = "utoipa")]
+mod openapi;
+mod patch;
+mod precision;
+mod resolution;
+#[cfg(feature = "schemars")]
+mod schema;
+mod strict;
+mod structure;
+mod subset;
+mod tile_size;
 mod tilejson;
+mod tilestats;
+mod validate;
 mod vector_layer;
+mod zoom;
 
+pub use crate::borrowed::*;
 pub use crate::bounds::*;
+pub use crate::camera::*;
 pub use crate::center::*;
+pub use crate::diff::*;
+pub use crate::error::*;
+pub use crate::extension::*;
+pub use crate::format::*;
+pub use crate::json_error::*;
+#[cfg(feature = "preserve_order")]
+pub use crate::key_order::*;
+pub use crate::lenient::*;
+pub use crate::merge::*;
+pub use crate::patch::*;
+pub use crate::precision::*;
+pub use crate::resolution::*;
+pub use crate::strict::*;
+pub use crate::structure::*;
+pub use crate::subset::*;
+pub use crate::tile_size::*;
 pub use crate::tilejson::*;
+pub use crate::tilestats::*;
+pub use crate::validate::*;
 pub use crate::vector_layer::*;
+pub use crate::zoom::*;
+#[cfg(feature = "derive")]
+pub use tilejson_derive::TileJsonExtension;
 
 #[cfg(doctest)]
 mod test_readme {
@@ -0,0 +1,401 @@
+use thiserror::Error;
+
+use crate::tile_size::TileSize;
+use crate::tilestats::{Tilestats, TilestatsLayer};
+use crate::vector_layer::VectorLayer;
+use crate::TileJSON;
+
+/// Determines how [`TileJSON::merge_with`] handles vector layers with the same id
+/// present in more than one source.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum LayerIdConflict {
+    /// Keep the layer from the first source that has it.
+    #[default]
+    KeepFirst,
+    /// Keep the layer from the last source that has it.
+    KeepLast,
+    /// Combine all layers with the same id into one: union of the fields,
+    /// the widest zoom range, and the first non-empty description and geometry.
+    Combine,
+    /// Fail the merge with [`MergeError::DuplicateLayer`].
+    Error,
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum MergeError {
+    #[error("At least one TileJSON source is required for merging")]
+    NoSources,
+    #[error("Vector layer {0} is present in more than one source")]
+    DuplicateLayer(String),
+    #[error("Sources have different tile sizes {0} and {1}")]
+    TileSizeMismatch(TileSize, TileSize),
+}
+
+impl TileJSON {
+    /// Merge several `TileJSON` sources into one composite document,
+    /// keeping the first vector layer when layer ids collide.
+    /// See [`TileJSON::merge_with`] for details.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::NoSources`] if `sources` is empty.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Bounds, TileJSON};
+    /// let a = tilejson! { "https://a/{z}/{x}/{y}".to_string(), name: "a".to_string(), minzoom: 2, maxzoom: 10, bounds: Bounds::new(-10., -10., 0., 0.) };
+    /// let b = tilejson! { "https://b/{z}/{x}/{y}".to_string(), name: "b".to_string(), minzoom: 0, maxzoom: 14, bounds: Bounds::new(0., 0., 10., 10.) };
+    /// let tj = TileJSON::merge(&[a, b]).unwrap();
+    /// assert_eq!(tj.name.as_deref(), Some("a,b"));
+    /// assert_eq!(tj.zoom_range().unwrap().to_string(), "0..=14");
+    /// assert_eq!(tj.bounds, Some(Bounds::new(-10., -10., 10., 10.)));
+    /// assert!(tj.tiles.is_empty());
+    /// ```
+    pub fn merge(sources: &[TileJSON]) -> Result<TileJSON, MergeError> {
+        Self::merge_with(sources, LayerIdConflict::default())
+    }
+
+    /// Merge several `TileJSON` sources into one composite document, similar to how
+    /// Mapbox composite sources and Martin's comma-joined sources behave.
+    ///
+    /// * `bounds` is the [union](crate::Bounds::union) of all bounds, which never crosses the antimeridian.
+    ///   If any source has no bounds, it is assumed to be global, and the result has no bounds.
+    /// * `minzoom` and `maxzoom` are the widest zoom range of all sources.
+    ///   If any source has no value, the spec default is assumed, and the result has no value.
+    /// * `vector_layers` and `tilestats` layers are concatenated in the source order,
+    ///   and layers with the same id are handled according to `on_conflict`.
+    /// * `attribution` and `description` are de-duplicated and joined with a space and a newline respectively.
+    /// * `name` values are joined with a comma.
+    /// * `tilejson`, `center`, `scheme`, `version`, `format`, and `encoding`
+    ///   are taken from the first source that has them.
+    ///   The `bearing` and `pitch` are taken from the same source as the `center`.
+    /// * `tile_size` must be the same in all sources, assuming 256 pixels if it is not set,
+    ///   because the zoom levels of the sources would not match otherwise.
+    /// * `data` and `grids` URLs are concatenated in the source order, without duplicates.
+    /// * Unrecognized `other` keys are combined, keeping the value from the first source that has the key.
+    ///
+    /// The `tiles` of the result are left empty, because the endpoints of the individual sources
+    /// do not serve the combined content. The caller must set them to the composite endpoint.
+    /// The `fillzoom`, `legend`, and `template` are dropped, because they describe how the tiles
+    /// of a single source are generated and displayed, and do not apply to the combined content.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::NoSources`] if `sources` is empty, and [`MergeError::DuplicateLayer`]
+    /// if a vector layer id is used by more than one source and `on_conflict` is [`LayerIdConflict::Error`].
+    /// Returns [`MergeError::TileSizeMismatch`] if the sources have different tile sizes.
+    pub fn merge_with(
+        sources: &[TileJSON],
+        on_conflict: LayerIdConflict,
+    ) -> Result<TileJSON, MergeError> {
+        let first = sources.first().ok_or(MergeError::NoSources)?;
+        let mut result = crate::tilejson! { tilejson: first.tilejson.clone(), tiles: Vec::new() };
+
+        let tile_size = first.tile_size.unwrap_or_default();
+        if let Some(other) = sources
+            .iter()
+            .map(|v| v.tile_size.unwrap_or_default())
+            .find(|v| *v != tile_size)
+        {
+            return Err(MergeError::TileSizeMismatch(tile_size, other));
+        }
+        result.tile_size = sources.iter().find_map(|v| v.tile_size);
+
+        result.bounds = sources
+            .iter()
+            .map(|v| v.bounds)
+            .reduce(|acc, v| Some(acc?.union(v?)))
+            .flatten();
+        result.minzoom = sources
+            .iter()
+            .map(|v| v.minzoom)
+            .reduce(|acc, v| Some(acc?.min(v?)))
+            .flatten();
+        result.maxzoom = sources
+            .iter()
+            .map(|v| v.maxzoom)
+            .reduce(|acc, v| Some(acc?.max(v?)))
+            .flatten();
+
+        result.name = join(sources.iter().map(|v| v.name.as_deref()), ",", false);
+        result.attribution = join(sources.iter().map(|v| v.attribution.as_deref()), " ", true);
+        result.description = join(sources.iter().map(|v| v.description.as_deref()), "\n", true);
+        if let Some(source) = sources.iter().find(|v| v.center.is_some()) {
+            result.center = source.center;
+            result.bearing = source.bearing;
+            result.pitch = source.pitch;
+        }
+        result.scheme = sources.iter().find_map(|v| v.scheme.clone());
+        result.version = sources.iter().find_map(|v| v.version.clone());
+        result.format = sources.iter().find_map(|v| v.format.clone());
+        result.encoding = sources.iter().find_map(|v| v.encoding.clone());
+        result.data = concat(sources.iter().map(|v| v.data.as_deref()));
+        result.grids = concat(sources.iter().map(|v| v.grids.as_deref()));
+        for source in sources {
+            for (key, value) in &source.other {
+                result
+                    .other
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+
+        if sources.iter().any(|v| v.vector_layers.is_some()) {
+            let layers = sources
+                .iter()
+                .flat_map(|v| v.vector_layers.iter().flatten());
+            result.vector_layers = Some(merge_layers(layers, on_conflict)?);
+        }
+        if sources.iter().any(|v| v.tilestats.is_some()) {
+            let layers = sources
+                .iter()
+                .flat_map(|v| v.tilestats.iter().flat_map(|v| &v.layers));
+            let layers = merge_stats(layers, on_conflict)?;
+            result.tilestats = Some(Tilestats {
+                layer_count: layers.len() as u64,
+                layers,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+/// Join non-empty values with a separator, optionally skipping duplicates
+fn join<'a>(
+    values: impl Iterator<Item = Option<&'a str>>,
+    separator: &str,
+    dedup: bool,
+) -> Option<String> {
+    let mut result: Vec<&str> = Vec::new();
+    for value in values.flatten().filter(|v| !v.is_empty()) {
+        if !dedup || !result.contains(&value) {
+            result.push(value);
+        }
+    }
+    if result.is_empty() {
+        None
+    } else {
+        Some(result.join(separator))
+    }
+}
+
+/// Concatenate lists of URLs, skipping duplicates. Returns `None` if no source has a list.
+fn concat<'a>(values: impl Iterator<Item = Option<&'a [String]>>) -> Option<Vec<String>> {
+    let mut result: Option<Vec<String>> = None;
+    for value in values.flatten() {
+        let result = result.get_or_insert_with(Vec::new);
+        for url in value {
+            if !result.contains(url) {
+                result.push(url.clone());
+            }
+        }
+    }
+    result
+}
+
+fn merge_layers<'a>(
+    layers: impl Iterator<Item = &'a VectorLayer>,
+    on_conflict: LayerIdConflict,
+) -> Result<Vec<VectorLayer>, MergeError> {
+    let mut result: Vec<VectorLayer> = Vec::new();
+    for layer in layers {
+        let Some(existing) = result.iter_mut().find(|v| v.id == layer.id) else {
+            result.push(layer.clone());
+            continue;
+        };
+        match on_conflict {
+            LayerIdConflict::KeepFirst => {}
+            LayerIdConflict::KeepLast => *existing = layer.clone(),
+            LayerIdConflict::Combine => {
+                for (key, value) in &layer.fields {
+                    existing.fields.entry(key.clone()).or_insert(value.clone());
+                }
+                for (key, value) in &layer.other {
+                    existing.other.entry(key.clone()).or_insert(value.clone());
+                }
+                if existing.description.is_none() {
+                    existing.description.clone_from(&layer.description);
+                }
+                if existing.geometry.is_none() {
+                    existing.geometry.clone_from(&layer.geometry);
+                }
+                existing.minzoom = existing.minzoom.zip(layer.minzoom).map(|(a, b)| a.min(b));
+                existing.maxzoom = existing.maxzoom.zip(layer.maxzoom).map(|(a, b)| a.max(b));
+            }
+            LayerIdConflict::Error => return Err(MergeError::DuplicateLayer(layer.id.clone())),
+        }
+    }
+    Ok(result)
+}
+
+fn merge_stats<'a>(
+    layers: impl Iterator<Item = &'a TilestatsLayer>,
+    on_conflict: LayerIdConflict,
+) -> Result<Vec<TilestatsLayer>, MergeError> {
+    let mut result: Vec<TilestatsLayer> = Vec::new();
+    for layer in layers {
+        let Some(existing) = result.iter_mut().find(|v| v.layer == layer.layer) else {
+            result.push(layer.clone());
+            continue;
+        };
+        match on_conflict {
+            // Statistics cannot be combined without the original data
+            LayerIdConflict::KeepFirst | LayerIdConflict::Combine => {}
+            LayerIdConflict::KeepLast => *existing = layer.clone(),
+            LayerIdConflict::Error => return Err(MergeError::DuplicateLayer(layer.layer.clone())),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{tilejson, Bounds, Zoom};
+
+    fn layer(id: &str, field: &str, minzoom: u8, maxzoom: u8) -> VectorLayer {
+        let mut layer = VectorLayer::new(
+            id.to_string(),
+            BTreeMap::from([(field.to_string(), "String".to_string())]),
+        );
+        layer.minzoom = Zoom::new(minzoom).ok();
+        layer.maxzoom = Zoom::new(maxzoom).ok();
+        layer
+    }
+
+    fn sources() -> Vec<TileJSON> {
+        vec![
+            tilejson! {
+                "https://a/{z}/{x}/{y}".to_string(),
+                name: "a".to_string(),
+                attribution: "© OSM".to_string(),
+                bounds: Bounds::new(170., -10., 180., 10.),
+                minzoom: 3,
+                maxzoom: 12,
+                vector_layers: vec![layer("roads", "name", 5, 12), layer("water", "kind", 3, 12)],
+            },
+            tilejson! {
+                "https://b/{z}/{x}/{y}".to_string(),
+                name: "b".to_string(),
+                attribution: "© OSM".to_string(),
+                bounds: Bounds::new(-180., -20., -170., 0.),
+                minzoom: 0,
+                maxzoom: 14,
+                vector_layers: vec![layer("roads", "ref", 0, 14)],
+            },
+            tilejson! {
+                "https://c/{z}/{x}/{y}".to_string(),
+                name: "c".to_string(),
+                attribution: "© Other".to_string(),
+                bounds: Bounds::new(175., -5., -175., 5.),
+                minzoom: 2,
+                maxzoom: 10,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_merge() {
+        let tj = TileJSON::merge(&sources()).unwrap();
+        assert_eq!(tj.name.as_deref(), Some("a,b,c"));
+        assert_eq!(tj.attribution.as_deref(), Some("© OSM © Other"));
+        assert_eq!(tj.minzoom.map(u8::from), Some(0));
+        assert_eq!(tj.maxzoom.map(u8::from), Some(14));
+        // Bounds on both sides of the antimeridian are not allowed to wrap around it
+        assert_eq!(tj.bounds, Some(Bounds::new(-180., -20., 180., 10.)));
+
+        let layers = tj.vector_layers.unwrap();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0], layer("roads", "name", 5, 12));
+        assert_eq!(layers[1].id, "water");
+    }
+
+    #[test]
+    fn test_merge_missing_values() {
+        let mut sources = sources();
+        sources[1].bounds = None;
+        sources[2].maxzoom = None;
+        let tj = TileJSON::merge(&sources).unwrap();
+        assert_eq!(tj.bounds, None);
+        assert_eq!(tj.minzoom.map(u8::from), Some(0));
+        assert_eq!(tj.maxzoom, None);
+
+        assert_eq!(TileJSON::merge(&[]), Err(MergeError::NoSources));
+    }
+
+    #[test]
+    fn test_merge_other_values() {
+        let mut sources = sources();
+        sources[0].data = Some(vec!["https://a/data.geojson".to_string()]);
+        sources[2].data = Some(vec![
+            "https://c/data.geojson".to_string(),
+            "https://a/data.geojson".to_string(),
+        ]);
+        sources[1].fillzoom = Zoom::new(10).ok();
+        sources[1].legend = Some("legend".to_string());
+        sources[1].other.insert("x-id".to_string(), json!("b"));
+        sources[2].other.insert("x-id".to_string(), json!("c"));
+        sources[2].other.insert("x-extra".to_string(), json!(1));
+        let tj = TileJSON::merge(&sources).unwrap();
+        assert_eq!(
+            tj.data,
+            Some(vec![
+                "https://a/data.geojson".to_string(),
+                "https://c/data.geojson".to_string()
+            ])
+        );
+        assert_eq!(tj.grids, None);
+        assert_eq!(tj.fillzoom, None);
+        assert_eq!(tj.legend, None);
+        assert_eq!(tj.other.get("x-id"), Some(&json!("b")));
+        assert_eq!(tj.other.get("x-extra"), Some(&json!(1)));
+    }
+
+    #[test]
+    fn test_merge_tile_size() {
+        let mut sources = sources();
+        for source in &mut sources {
+            source.tile_size = Some(TileSize::PX_512);
+        }
+        let tj = TileJSON::merge(&sources).unwrap();
+        assert_eq!(tj.tile_size, Some(TileSize::PX_512));
+
+        sources[2].tile_size = None;
+        assert_eq!(
+            TileJSON::merge(&sources),
+            Err(MergeError::TileSizeMismatch(
+                TileSize::PX_512,
+                TileSize::PX_256
+            ))
+        );
+    }
+
+    #[test]
+    fn test_layer_conflicts() {
+        let merge = |on_conflict| TileJSON::merge_with(&sources(), on_conflict);
+
+        let layers = merge(LayerIdConflict::KeepLast)
+            .unwrap()
+            .vector_layers
+            .unwrap();
+        assert_eq!(layers[0], layer("roads", "ref", 0, 14));
+
+        let layers = merge(LayerIdConflict::Combine)
+            .unwrap()
+            .vector_layers
+            .unwrap();
+        assert_eq!(layers[0].minzoom.map(u8::from), Some(0));
+        assert_eq!(layers[0].maxzoom.map(u8::from), Some(14));
+        assert_eq!(
+            layers[0].fields.keys().collect::<Vec<_>>(),
+            vec!["name", "ref"]
+        );
+
+        assert_eq!(
+            merge(LayerIdConflict::Error),
+            Err(MergeError::DuplicateLayer("roads".to_string()))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Formatter;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// A float that is also accepted when encoded as an integer.
+///
+/// Most parsers convert integers to floats on their own, but a self-describing format
+/// may reject an integer where a float is expected, e.g. `"center": [1, 2, 3]`.
+/// The type of the value is only inspected in human-readable formats, because binary formats
+/// like bincode cannot report it. Binary formats, including CBOR, must encode floats as floats,
+/// which is what this crate does when serializing them.
+pub(crate) struct LooseF64(pub f64);
+
+impl<'de> Deserialize<'de> for LooseF64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LooseF64Visitor;
+
+        impl Visitor<'_> for LooseF64Visitor {
+            type Value = LooseF64;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a number")
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<LooseF64, E> {
+                Ok(LooseF64(v))
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            fn visit_i64<E: Error>(self, v: i64) -> Result<LooseF64, E> {
+                Ok(LooseF64(v as f64))
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            fn visit_u64<E: Error>(self, v: u64) -> Result<LooseF64, E> {
+                Ok(LooseF64(v as f64))
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            fn visit_i128<E: Error>(self, v: i128) -> Result<LooseF64, E> {
+                Ok(LooseF64(v as f64))
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            fn visit_u128<E: Error>(self, v: u128) -> Result<LooseF64, E> {
+                Ok(LooseF64(v as f64))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(LooseF64Visitor)
+        } else {
+            deserializer.deserialize_f64(LooseF64Visitor)
+        }
+    }
+}
+
+/// Deserialize an optional float field, accepting integers.
+/// Use with `#[serde(default, deserialize_with)]`.
+pub(crate) fn deserialize_opt_f64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f64>, D::Error> {
+    Option::<LooseF64>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
@@ -0,0 +1,171 @@
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::{KnownFormat, RefOr};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::{
+    Bounds, Center, GeometryType, RasterDemEncoding, TileFormat, TileSize, Zoom, MAX_ZOOM,
+};
+
+fn number() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::Number)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Double)))
+}
+
+impl PartialSchema for Bounds {
+    fn schema() -> RefOr<Schema> {
+        ArrayBuilder::new()
+            .items(number())
+            .min_items(Some(4))
+            .max_items(Some(4))
+            .description(Some(
+                "The bounds as [left, bottom, right, top] in WGS 84 degrees",
+            ))
+            .examples([serde_json::json!([
+                -180,
+                -85.051_128_779_806_59,
+                180,
+                85.051_128_779_806_6
+            ])])
+            .into()
+    }
+}
+
+impl ToSchema for Bounds {}
+
+impl PartialSchema for Center {
+    fn schema() -> RefOr<Schema> {
+        ArrayBuilder::new()
+            .items(number())
+            .min_items(Some(2))
+            .max_items(Some(3))
+            .description(Some(
+                "The default location as [longitude, latitude, zoom], with an optional zoom",
+            ))
+            .examples([serde_json::json!([
+                -76.275_329_586_789,
+                39.153_492_567_373,
+                8
+            ])])
+            .into()
+    }
+}
+
+impl ToSchema for Center {}
+
+impl PartialSchema for Zoom {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .minimum(Some(0))
+            .maximum(Some(MAX_ZOOM))
+            .description(Some("A zoom level"))
+            .into()
+    }
+}
+
+impl ToSchema for Zoom {}
+
+impl PartialSchema for TileSize {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .minimum(Some(1))
+            .description(Some(
+                "Size of the square tiles in pixels, usually 256 or 512",
+            ))
+            .examples([256, 512])
+            .into()
+    }
+}
+
+impl ToSchema for TileSize {}
+
+impl PartialSchema for TileFormat {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("Encoding of the tile data"))
+            .examples(["png", "jpg", "webp", "avif", "pbf", "mvt", "mlt", "json"])
+            .into()
+    }
+}
+
+impl ToSchema for TileFormat {}
+
+impl PartialSchema for RasterDemEncoding {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("Encoding of the elevation in raster-dem tiles"))
+            .examples(["mapbox", "terrarium", "custom"])
+            .into()
+    }
+}
+
+impl ToSchema for RasterDemEncoding {}
+
+impl PartialSchema for GeometryType {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("Geometry type of the features in a layer"))
+            .examples([
+                "Point",
+                "LineString",
+                "Polygon",
+                "MultiPoint",
+                "MultiLineString",
+                "MultiPolygon",
+            ])
+            .into()
+    }
+}
+
+impl ToSchema for GeometryType {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use utoipa::OpenApi;
+
+    use crate::{TileJSON, VectorLayer};
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(TileJSON, VectorLayer)))]
+    struct ApiDoc;
+
+    fn schemas() -> Value {
+        serde_json::to_value(ApiDoc::openapi().components.unwrap().schemas).unwrap()
+    }
+
+    #[test]
+    fn test_fixed_length_arrays() {
+        let schemas = schemas();
+        let bounds = &schemas["Bounds"];
+        assert_eq!(bounds["type"], "array");
+        assert_eq!(
+            (&bounds["minItems"], &bounds["maxItems"]),
+            (&json!(4), &json!(4))
+        );
+        assert_eq!(bounds["items"]["type"], "number");
+        let center = &schemas["Center"];
+        assert_eq!(
+            (&center["minItems"], &center["maxItems"]),
+            (&json!(2), &json!(3))
+        );
+    }
+
+    #[test]
+    fn test_tilejson() {
+        let schemas = schemas();
+        let tilejson = &schemas["TileJSON"];
+        assert_eq!(tilejson["required"], json!(["tilejson", "tiles"]));
+        assert_eq!(
+            tilejson["properties"]["maxzoom"]["oneOf"][1]["$ref"],
+            "#/components/schemas/Zoom"
+        );
+        assert_eq!(schemas["Zoom"]["maximum"], 30);
+        assert_eq!(schemas["VectorLayer"]["required"], json!(["id", "fields"]));
+    }
+}
@@ -0,0 +1,349 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::diff::values_equal;
+use crate::json_error::{from_value, JsonError};
+use crate::validate::ValidationError;
+use crate::TileJSON;
+
+/// A single [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch operation.
+///
+/// A JSON Patch document is an array of operations, and can be parsed as `Vec<PatchOperation>`.
+/// The `path` and `from` values are [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointers.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+#[derive(Error, Debug)]
+pub enum PatchError {
+    #[error("Invalid JSON pointer {0:?}")]
+    InvalidPointer(String),
+    #[error("Path {0:?} does not exist in the document")]
+    PathNotFound(String),
+    #[error("Cannot move {0:?} into one of its children")]
+    MoveIntoChild(String),
+    #[error("Test operation failed for path {0:?}")]
+    TestFailed(String),
+    #[error("Patched document is not a valid TileJSON: {0}")]
+    Deserialize(#[from] JsonError),
+    #[error("Patched document is not a valid TileJSON: {0}")]
+    Validation(#[from] ValidationError),
+}
+
+impl TileJSON {
+    /// Apply an [RFC 7386](https://www.rfc-editor.org/rfc/rfc7386) JSON Merge Patch to this document.
+    ///
+    /// The patch applies to the JSON representation of the document, so it may change
+    /// both the typed fields and the values in `other`. The patched document is parsed
+    /// and [validated](TileJSON::validate), and this document is only modified if both succeed.
+    ///
+    /// # Errors
+    /// Returns an error if the patched document is not a valid `TileJSON`.
+    ///
+    /// ```
+    /// # use tilejson::tilejson;
+    /// let mut tj = tilejson! { "https://example.com/".to_string(), name: "a".to_string() };
+    /// tj.apply_merge_patch(&serde_json::json!({ "name": null, "maxzoom": 10, "foo": "bar" })).unwrap();
+    /// assert_eq!(tj.name, None);
+    /// assert_eq!(tj.maxzoom.unwrap(), 10);
+    /// assert_eq!(tj.other["foo"], "bar");
+    ///
+    /// // Invalid documents are rejected, and the original document is not modified
+    /// assert!(tj.apply_merge_patch(&serde_json::json!({ "minzoom": 20 })).is_err());
+    /// assert_eq!(tj.minzoom, None);
+    /// ```
+    pub fn apply_merge_patch(&mut self, patch: &Value) -> Result<(), PatchError> {
+        let mut doc = serde_json::to_value(&*self).map_err(JsonError::from)?;
+        merge_patch(&mut doc, patch);
+        self.replace_with(doc)
+    }
+
+    /// Apply an [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch to this document.
+    ///
+    /// The patch applies to the JSON representation of the document, so it may change
+    /// both the typed fields and the values in `other`. All operations are applied in order,
+    /// and the patched document is parsed and [validated](TileJSON::validate).
+    /// This document is only modified if all operations and the validation succeed.
+    ///
+    /// # Errors
+    /// Returns an error if any operation fails, or if the patched document is not a valid `TileJSON`.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, PatchOperation};
+    /// let mut tj = tilejson! { "https://a.example.com/".to_string() };
+    /// let patch: Vec<PatchOperation> = serde_json::from_str(r#"[
+    ///     { "op": "add", "path": "/tiles/-", "value": "https://b.example.com/" },
+    ///     { "op": "add", "path": "/attribution", "value": "© Example" }
+    /// ]"#).unwrap();
+    /// tj.apply_json_patch(&patch).unwrap();
+    /// assert_eq!(tj.tiles.len(), 2);
+    /// assert_eq!(tj.attribution.as_deref(), Some("© Example"));
+    /// ```
+    pub fn apply_json_patch(&mut self, patch: &[PatchOperation]) -> Result<(), PatchError> {
+        let mut doc = serde_json::to_value(&*self).map_err(JsonError::from)?;
+        for op in patch {
+            apply_operation(&mut doc, op)?;
+        }
+        self.replace_with(doc)
+    }
+
+    fn replace_with(&mut self, doc: Value) -> Result<(), PatchError> {
+        let patched: TileJSON = from_value(doc)?;
+        patched.validate()?;
+        *self = patched;
+        Ok(())
+    }
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+fn apply_operation(doc: &mut Value, op: &PatchOperation) -> Result<(), PatchError> {
+    match op {
+        PatchOperation::Add { path, value } => add(doc, path, value.clone()),
+        PatchOperation::Remove { path } => remove(doc, path).map(|_| ()),
+        PatchOperation::Replace { path, value } => {
+            let target = doc
+                .pointer_mut(validate_pointer(path)?)
+                .ok_or_else(|| PatchError::PathNotFound(path.clone()))?;
+            *target = value.clone();
+            Ok(())
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{from}/")) {
+                return Err(PatchError::MoveIntoChild(from.clone()));
+            }
+            let value = remove(doc, from)?;
+            add(doc, path, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = doc
+                .pointer(validate_pointer(from)?)
+                .ok_or_else(|| PatchError::PathNotFound(from.clone()))?
+                .clone();
+            add(doc, path, value)
+        }
+        PatchOperation::Test { path, value } => {
+            // Numbers are compared by value, so that `1` is equal to `1.0` (RFC 6902 section 4.6)
+            let target = doc.pointer(validate_pointer(path)?);
+            if target.is_some_and(|v| values_equal(v, value, 0.0)) {
+                Ok(())
+            } else {
+                Err(PatchError::TestFailed(path.clone()))
+            }
+        }
+    }
+}
+
+fn validate_pointer(path: &str) -> Result<&str, PatchError> {
+    if path.is_empty() || path.starts_with('/') {
+        Ok(path)
+    } else {
+        Err(PatchError::InvalidPointer(path.to_string()))
+    }
+}
+
+/// Split a JSON pointer into the pointer to the parent container and the unescaped last token
+fn split_pointer(path: &str) -> Result<(&str, String), PatchError> {
+    validate_pointer(path)?;
+    let (parent, token) = path
+        .rsplit_once('/')
+        .ok_or_else(|| PatchError::InvalidPointer(path.to_string()))?;
+    Ok((parent, token.replace("~1", "/").replace("~0", "~")))
+}
+
+fn parent_mut<'a>(
+    doc: &'a mut Value,
+    parent: &str,
+    path: &str,
+) -> Result<&'a mut Value, PatchError> {
+    doc.pointer_mut(parent)
+        .ok_or_else(|| PatchError::PathNotFound(path.to_string()))
+}
+
+fn add(doc: &mut Value, path: &str, value: Value) -> Result<(), PatchError> {
+    if path.is_empty() {
+        *doc = value;
+        return Ok(());
+    }
+    let (parent, token) = split_pointer(path)?;
+    match parent_mut(doc, parent, path)? {
+        Value::Object(map) => {
+            map.insert(token, value);
+        }
+        Value::Array(arr) => {
+            let idx = if token == "-" {
+                arr.len()
+            } else {
+                array_index(&token, arr.len() + 1, path)?
+            };
+            arr.insert(idx, value);
+        }
+        _ => return Err(PatchError::PathNotFound(path.to_string())),
+    }
+    Ok(())
+}
+
+fn remove(doc: &mut Value, path: &str) -> Result<Value, PatchError> {
+    let (parent, token) = split_pointer(path)?;
+    let removed = match parent_mut(doc, parent, path)? {
+        Value::Object(map) => map.remove(&token),
+        Value::Array(arr) => {
+            let idx = array_index(&token, arr.len(), path)?;
+            Some(arr.remove(idx))
+        }
+        _ => None,
+    };
+    removed.ok_or_else(|| PatchError::PathNotFound(path.to_string()))
+}
+
+fn array_index(token: &str, len: usize, path: &str) -> Result<usize, PatchError> {
+    let valid = !token.is_empty() && (token == "0" || !token.starts_with('0'));
+    token
+        .parse::<usize>()
+        .ok()
+        .filter(|idx| valid && *idx < len)
+        .ok_or_else(|| PatchError::PathNotFound(path.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{tilejson, Bounds, Center};
+
+    fn patch(ops: Value) -> Vec<PatchOperation> {
+        serde_json::from_value(ops).unwrap()
+    }
+
+    #[test]
+    fn test_merge_patch() {
+        let mut tj = tilejson! { "https://example.com/".to_string(), name: "a".to_string() };
+        tj.other.insert("ext".to_string(), json!({"a": 1, "b": 2}));
+        tj.apply_merge_patch(&json!({
+            "description": "desc",
+            "ext": {"a": null, "c": 3},
+        }))
+        .unwrap();
+        assert_eq!(tj.name.as_deref(), Some("a"));
+        assert_eq!(tj.description.as_deref(), Some("desc"));
+        assert_eq!(tj.other["ext"], json!({"b": 2, "c": 3}));
+
+        // Type errors are detected
+        assert!(matches!(
+            tj.apply_merge_patch(&json!({ "maxzoom": "high" })),
+            Err(PatchError::Deserialize(_))
+        ));
+        assert!(matches!(
+            tj.apply_merge_patch(&json!({ "tiles": [] })),
+            Err(PatchError::Validation(ValidationError::NoTiles))
+        ));
+        assert_eq!(tj.tiles.len(), 1);
+    }
+
+    #[test]
+    fn test_json_patch() {
+        let mut tj = tilejson! {
+            tiles: vec!["https://a/".to_string(), "https://b/".to_string()],
+            attribution: "x".to_string(),
+        };
+        tj.apply_json_patch(&patch(json!([
+            { "op": "test", "path": "/attribution", "value": "x" },
+            { "op": "replace", "path": "/attribution", "value": "y" },
+            { "op": "remove", "path": "/tiles/0" },
+            { "op": "copy", "from": "/attribution", "path": "/description" },
+            { "op": "move", "from": "/description", "path": "/x~1y" },
+            { "op": "add", "path": "/minzoom", "value": 2 },
+        ])))
+        .unwrap();
+        assert_eq!(tj.tiles, vec!["https://b/".to_string()]);
+        assert_eq!(tj.attribution.as_deref(), Some("y"));
+        assert_eq!(tj.description, None);
+        assert_eq!(tj.other["x/y"], "y");
+        assert_eq!(tj.minzoom.map(u8::from), Some(2));
+    }
+
+    #[test]
+    fn test_json_patch_numbers() {
+        let mut tj = tilejson! {
+            "https://a/".to_string(),
+            center: Center::new(1.0, 2.5, 3),
+            bounds: Bounds::new(-10., -10., 10., 10.),
+        };
+        // Integers are equal to floats with the same value, including in nested values
+        tj.apply_json_patch(&patch(json!([
+            { "op": "test", "path": "/center/0", "value": 1 },
+            { "op": "test", "path": "/center/2", "value": 3.0 },
+            { "op": "test", "path": "/bounds", "value": [-10, -10, 10, 10] },
+        ])))
+        .unwrap();
+        assert!(matches!(
+            tj.apply_json_patch(&patch(json!([
+                { "op": "test", "path": "/center/1", "value": 2 },
+            ]))),
+            Err(PatchError::TestFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_json_patch_errors() {
+        let mut tj = tilejson! { "https://a/".to_string(), minzoom: 1 };
+        let original = tj.clone();
+        let mut err = |ops| tj.apply_json_patch(&patch(ops)).unwrap_err();
+
+        assert!(matches!(
+            err(json!([{ "op": "remove", "path": "/name" }])),
+            PatchError::PathNotFound(_)
+        ));
+        assert!(matches!(
+            err(json!([{ "op": "remove", "path": "name" }])),
+            PatchError::InvalidPointer(_)
+        ));
+        assert!(matches!(
+            err(json!([{ "op": "add", "path": "/tiles/5", "value": "x" }])),
+            PatchError::PathNotFound(_)
+        ));
+        assert!(matches!(
+            err(json!([{ "op": "test", "path": "/minzoom", "value": 2 }])),
+            PatchError::TestFailed(_)
+        ));
+        assert!(matches!(
+            err(json!([{ "op": "move", "from": "/tiles", "path": "/tiles/0" }])),
+            PatchError::MoveIntoChild(_)
+        ));
+        // The first operation succeeds, but the second makes the document invalid
+        assert!(matches!(
+            err(json!([
+                { "op": "replace", "path": "/minzoom", "value": 5 },
+                { "op": "add", "path": "/maxzoom", "value": 3 },
+            ])),
+            PatchError::Validation(ValidationError::InvalidZoomRange(5, 3))
+        ));
+        assert_eq!(tj, original);
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{Serialize, Serializer};
+
+use crate::bounds::Bounds;
+use crate::center::Center;
+use crate::tile_size::TileSize;
+use crate::zoom::MAX_ZOOM;
+use crate::TileJSON;
+
+/// Coordinate precision used when rounding [`Bounds`] and [`Center`] values.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Precision {
+    /// Round to a fixed number of decimal places.
+    Decimals(u8),
+    /// Round to the number of decimal places needed for a one-pixel precision
+    /// at the tileset's `maxzoom` and `tileSize`, see [`decimals_for_tile_size`].
+    /// Tiles are assumed to be 256 pixels if the `tileSize` is not set.
+    MaxZoom,
+}
+
+/// Number of decimal places of a coordinate in degrees needed to represent
+/// a location with one-pixel precision at the given zoom level of 256-pixel tiles.
+///
+/// ```
+/// # use tilejson::decimals_for_zoom;
+/// assert_eq!(decimals_for_zoom(0), 0);
+/// assert_eq!(decimals_for_zoom(10), 3);
+/// assert_eq!(decimals_for_zoom(14), 5);
+/// assert_eq!(decimals_for_zoom(30), 9);
+/// ```
+#[must_use]
+pub fn decimals_for_zoom(zoom: u8) -> u8 {
+    decimals_for_tile_size(zoom, TileSize::PX_256)
+}
+
+/// Number of decimal places of a coordinate in degrees needed to represent
+/// a location with one-pixel precision at the given zoom level and tile size.
+///
+/// ```
+/// # use tilejson::{decimals_for_tile_size, TileSize};
+/// assert_eq!(decimals_for_tile_size(13, TileSize::PX_256), 4);
+/// assert_eq!(decimals_for_tile_size(13, TileSize::PX_512), 5);
+/// ```
+#[must_use]
+pub fn decimals_for_tile_size(zoom: u8, tile_size: TileSize) -> u8 {
+    let pixels = f64::from(tile_size.get()) * 2_f64.powi(i32::from(zoom.min(MAX_ZOOM)));
+    let degrees_per_pixel = 360.0 / pixels;
+    let decimals = (-degrees_per_pixel.log10()).ceil().max(0.0);
+    // The value is between 0 and 10 for all valid zooms
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    {
+        decimals as u8
+    }
+}
+
+fn round(value: f64, decimals: u8) -> f64 {
+    round_with(value, decimals, f64::round)
+}
+
+/// Round to the given number of decimal places using a rounding function like [`f64::floor`].
+/// Values that are already at that precision are kept as is,
+/// even if the scaling introduced a small floating point error.
+fn round_with(value: f64, decimals: u8, func: fn(f64) -> f64) -> f64 {
+    let factor = 10_f64.powi(i32::from(decimals));
+    let scaled = value * factor;
+    let nearest = scaled.round();
+    let scaled = if (scaled - nearest).abs() < 1e-9 {
+        nearest
+    } else {
+        func(scaled)
+    };
+    // Adding zero turns a negative zero into a positive one
+    scaled / factor + 0.0
+}
+
+impl Bounds {
+    /// Round all coordinates outward to the given number of decimal places,
+    /// so that the rounded bounds still contain the original ones.
+    ///
+    /// ```
+    /// # use tilejson::Bounds;
+    /// assert_eq!(
+    ///     Bounds::MAX_TILED.round_to(4),
+    ///     Bounds::new(-180.0, -85.0512, 180.0, 85.0512)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn round_to(self, decimals: u8) -> Self {
+        Self::new(
+            round_with(self.left, decimals, f64::floor),
+            round_with(self.bottom, decimals, f64::floor),
+            round_with(self.right, decimals, f64::ceil),
+            round_with(self.top, decimals, f64::ceil),
+        )
+    }
+}
+
+impl Center {
+    /// Round the longitude and latitude to the given number of decimal places.
+    ///
+    /// ```
+    /// # use tilejson::Center;
+    /// assert_eq!(
+    ///     Center::new(-76.275_329_586_789, 39.153_492_567_373, 8).round_to(3),
+    ///     Center::new(-76.275, 39.153, 8)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn round_to(self, decimals: u8) -> Self {
+        Self {
+            longitude: round(self.longitude, decimals),
+            latitude: round(self.latitude, decimals),
+            ..self
+        }
+    }
+}
+
+impl TileJSON {
+    /// Round the `bounds` coordinates outward, and the `center` coordinates to the nearest value,
+    /// at the given precision.
+    pub fn round_coordinates(&mut self, precision: Precision) {
+        let decimals = match precision {
+            Precision::Decimals(v) => v,
+            Precision::MaxZoom => decimals_for_tile_size(
+                self.maxzoom.map_or(MAX_ZOOM, u8::from),
+                self.tile_size.unwrap_or(TileSize::PX_256),
+            ),
+        };
+        self.bounds = self.bounds.map(|v| v.round_to(decimals));
+        self.center = self.center.map(|v| v.round_to(decimals));
+    }
+
+    /// Get a serializable view of this document with the `bounds` and `center`
+    /// coordinates rounded to the given precision. The document itself is not modified.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Bounds, Precision};
+    /// let tj = tilejson! { "https://example.com/".to_string(), bounds: Bounds::MAX_TILED, maxzoom: 10 };
+    /// assert_eq!(
+    ///     serde_json::to_string(&tj.with_precision(Precision::MaxZoom)).unwrap(),
+    ///     r#"{"tilejson":"3.0.0","tiles":["https://example.com/"],"bounds":[-180.0,-85.052,180.0,85.052],"maxzoom":10}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_precision(&self, precision: Precision) -> RoundedTileJSON<'_> {
+        RoundedTileJSON {
+            tilejson: self,
+            precision,
+        }
+    }
+}
+
+/// A serializable view of a [`TileJSON`] with rounded coordinates,
+/// created by [`TileJSON::with_precision`].
+#[derive(Debug, Copy, Clone)]
+pub struct RoundedTileJSON<'a> {
+    tilejson: &'a TileJSON,
+    precision: Precision,
+}
+
+impl Serialize for RoundedTileJSON<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tilejson = self.tilejson.clone();
+        tilejson.round_coordinates(self.precision);
+        tilejson.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tilejson;
+
+    #[test]
+    fn test_round() {
+        let round = |v, d| round(v, d).to_string();
+        assert_eq!(round(1.234_567, 0), "1");
+        assert_eq!(round(-1.234_567, 2), "-1.23");
+        assert_eq!(round(-0.000_1, 2), "0");
+        assert_eq!(round(85.051_128_779_806_59, 6), "85.051129");
+        assert_eq!(round_with(0.3, 1, f64::ceil).to_string(), "0.3");
+        assert_eq!(round_with(-0.04, 1, f64::ceil).to_string(), "0");
+    }
+
+    #[test]
+    fn test_round_bounds_outward() {
+        let bounds = Bounds::new(5.04, 45.06, 10.04, 48.06);
+        let rounded = bounds.round_to(1);
+        assert_eq!(rounded, Bounds::new(5.0, 45.0, 10.1, 48.1));
+        assert_eq!(rounded.intersection(bounds), Some(bounds));
+        assert_eq!(
+            Bounds::new(1.5, -2.5, 3.5, 4.5).round_to(0),
+            Bounds::new(1.0, -3.0, 4.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_round_coordinates() {
+        let mut tj = tilejson! {
+            "https://example.com/".to_string(),
+            bounds: Bounds::MAX_TILED,
+            center: Center::new(-76.275_329_586_789, 39.153_492_567_373, 8),
+        };
+        tj.round_coordinates(Precision::MaxZoom);
+        assert_eq!(
+            tj.bounds,
+            Some(Bounds::new(-180.0, -85.051_128_78, 180.0, 85.051_128_78))
+        );
+
+        tj.round_coordinates(Precision::Decimals(2));
+        assert_eq!(tj.bounds, Some(Bounds::new(-180.0, -85.06, 180.0, 85.06)));
+        assert_eq!(tj.center, Some(Center::new(-76.28, 39.15, 8)));
+    }
+
+    #[test]
+    fn test_round_coordinates_tile_size() {
+        let center = Center::new(-76.275_329_586_789, 39.153_492_567_373, 8);
+        let mut tj = tilejson! { "https://example.com/".to_string(), center: center, maxzoom: 13 };
+        tj.round_coordinates(Precision::MaxZoom);
+        assert_eq!(tj.center, Some(Center::new(-76.2753, 39.1535, 8)));
+
+        // 512-pixel tiles show twice as much detail at the same zoom
+        tj.center = Some(center);
+        tj.tile_size = Some(TileSize::PX_512);
+        tj.round_coordinates(Precision::MaxZoom);
+        assert_eq!(tj.center, Some(Center::new(-76.275_33, 39.153_49, 8)));
+    }
+}
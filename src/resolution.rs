@@ -0,0 +1,160 @@
+use std::f64::consts::PI;
+
+use crate::center::Center;
+use crate::zoom::{Zoom, ZoomRange};
+
+/// Equatorial radius of the WGS 84 ellipsoid, as used by the Web Mercator projection, in meters.
+pub const EARTH_RADIUS: f64 = 6_378_137.0;
+
+/// Circumference of the Earth at the equator in the Web Mercator projection, in meters.
+pub const EARTH_CIRCUMFERENCE: f64 = 2.0 * PI * EARTH_RADIUS;
+
+/// The standardized rendering pixel size of 0.28 mm used by the OGC WMTS and WMS
+/// specifications to compute scale denominators, in meters.
+pub const OGC_PIXEL_SIZE: f64 = 0.000_28;
+
+/// Ground resolution in meters per pixel at the equator, for the given zoom and tile size.
+/// Fractional zooms are supported.
+///
+/// ```
+/// # use tilejson::ground_resolution;
+/// assert_eq!(format!("{:.4}", ground_resolution(0.0, 256)), "156543.0339");
+/// assert_eq!(format!("{:.4}", ground_resolution(14.0, 512)), "4.7773");
+/// ```
+#[must_use]
+pub fn ground_resolution(zoom: f64, tile_size: u32) -> f64 {
+    EARTH_CIRCUMFERENCE / (f64::from(tile_size) * zoom.exp2())
+}
+
+/// Ground resolution in meters per pixel at the given latitude in degrees.
+/// The Web Mercator projection stretches the map away from the equator,
+/// so the same pixel covers less ground at higher latitudes.
+///
+/// ```
+/// # use tilejson::latitude_resolution;
+/// assert_eq!(format!("{:.4}", latitude_resolution(0.0, 60.0, 256)), "78271.5170");
+/// ```
+#[must_use]
+pub fn latitude_resolution(zoom: f64, latitude: f64, tile_size: u32) -> f64 {
+    ground_resolution(zoom, tile_size) * latitude.to_radians().cos()
+}
+
+/// OGC scale denominator for the given zoom and tile size, assuming the
+/// [standardized pixel size](OGC_PIXEL_SIZE) of 0.28 mm.
+///
+/// ```
+/// # use tilejson::scale_denominator;
+/// assert_eq!(format!("{:.4}", scale_denominator(0.0, 256)), "559082264.0287");
+/// ```
+#[must_use]
+pub fn scale_denominator(zoom: f64, tile_size: u32) -> f64 {
+    ground_resolution(zoom, tile_size) / OGC_PIXEL_SIZE
+}
+
+/// The fractional zoom at which the ground resolution at the equator matches the given
+/// resolution in meters per pixel. Round the result up to get the first integer zoom
+/// with at least the desired resolution, e.g. to choose the `maxzoom` of a raster source.
+///
+/// ```
+/// # use tilejson::{ground_resolution, zoom_for_resolution};
+/// assert_eq!(format!("{:.4}", zoom_for_resolution(ground_resolution(12.0, 256), 256)), "12.0000");
+/// assert_eq!(zoom_for_resolution(10.0, 256).ceil(), 14.0);
+/// ```
+#[must_use]
+pub fn zoom_for_resolution(resolution: f64, tile_size: u32) -> f64 {
+    (EARTH_CIRCUMFERENCE / (f64::from(tile_size) * resolution)).log2()
+}
+
+/// The fractional zoom for the given OGC scale denominator, see [`scale_denominator`].
+#[must_use]
+pub fn zoom_for_scale_denominator(scale_denominator: f64, tile_size: u32) -> f64 {
+    zoom_for_resolution(scale_denominator * OGC_PIXEL_SIZE, tile_size)
+}
+
+/// Resolution and scale of a single zoom level, as returned by [`ZoomRange::resolutions`].
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct ZoomResolution {
+    pub zoom: Zoom,
+    /// Ground resolution at the equator, in meters per pixel.
+    pub resolution: f64,
+    /// OGC scale denominator.
+    pub scale_denominator: f64,
+}
+
+impl ZoomRange {
+    /// Get the resolution and scale denominator of each zoom level in this range,
+    /// e.g. to generate the tile matrix set of a WMTS capabilities document.
+    ///
+    /// ```
+    /// # use tilejson::{Zoom, ZoomRange};
+    /// let range = ZoomRange::new(Zoom::MIN, Zoom::new(2).unwrap()).unwrap();
+    /// let table = range.resolutions(512);
+    /// assert_eq!(table.len(), 3);
+    /// assert_eq!(format!("{:.4}", table[2].resolution), "19567.8792");
+    /// ```
+    #[must_use]
+    pub fn resolutions(self, tile_size: u32) -> Vec<ZoomResolution> {
+        self.iter()
+            .map(|zoom| ZoomResolution {
+                zoom,
+                resolution: ground_resolution(f64::from(zoom), tile_size),
+                scale_denominator: scale_denominator(f64::from(zoom), tile_size),
+            })
+            .collect()
+    }
+}
+
+impl Center {
+    /// Ground resolution in meters per pixel at this location and zoom,
+    /// or `None` if the zoom is not set.
+    ///
+    /// ```
+    /// # use tilejson::Center;
+    /// let center = Center::new(10.0, 60.0, 1);
+    /// assert_eq!(format!("{:.4}", center.resolution(256).unwrap()), "39135.7585");
+    /// ```
+    #[must_use]
+    pub fn resolution(&self, tile_size: u32) -> Option<f64> {
+        self.zoom
+            .map(|zoom| latitude_resolution(zoom, self.latitude, tile_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9 * b.abs().max(1.0), "{a} != {b}");
+    }
+
+    #[test]
+    fn test_resolution() {
+        // A 512-pixel tile covers the same area as four 256-pixel tiles one zoom level higher
+        assert_close(ground_resolution(5.0, 512), ground_resolution(6.0, 256));
+        assert_close(
+            ground_resolution(0.0, 256) / 2.0,
+            ground_resolution(1.0, 256),
+        );
+        assert_close(
+            ground_resolution(2.5, 256),
+            ground_resolution(2.0, 256) / 2_f64.sqrt(),
+        );
+        assert_close(
+            latitude_resolution(3.0, -60.0, 256),
+            ground_resolution(4.0, 256),
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        for zoom in ZoomRange::FULL {
+            let zoom = f64::from(zoom);
+            assert_close(zoom_for_resolution(ground_resolution(zoom, 256), 256), zoom);
+            assert_close(
+                zoom_for_scale_denominator(scale_denominator(zoom, 512), 512),
+                zoom,
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{
+    Bounds, Center, GeometryType, RasterDemEncoding, TileFormat, TileJSON, TileSize, Zoom, MAX_ZOOM,
+};
+
+impl TileJSON {
+    /// Generate the JSON Schema of the `TileJSON` document, as read and written by this crate,
+    /// including the supported extensions. Requires the `schemars` feature.
+    ///
+    /// ```
+    /// # use tilejson::TileJSON;
+    /// let schema = TileJSON::schema();
+    /// assert_eq!(schema.pointer("/$defs/Zoom/maximum").unwrap(), 30);
+    /// assert_eq!(schema.pointer("/$defs/Bounds/minItems").unwrap(), 4);
+    /// assert_eq!(schema.pointer("/required").unwrap(), &serde_json::json!(["tilejson", "tiles"]));
+    /// ```
+    #[must_use]
+    pub fn schema() -> Schema {
+        schemars::schema_for!(TileJSON)
+    }
+}
+
+impl JsonSchema for Zoom {
+    fn schema_name() -> Cow<'static, str> {
+        "Zoom".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A zoom level",
+            "type": "integer",
+            "minimum": 0,
+            "maximum": MAX_ZOOM,
+        })
+    }
+}
+
+impl JsonSchema for TileSize {
+    fn schema_name() -> Cow<'static, str> {
+        "TileSize".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Size of the square tiles in pixels, usually 256 or 512",
+            "type": "integer",
+            "minimum": 1,
+            "examples": [256, 512],
+        })
+    }
+}
+
+impl JsonSchema for TileFormat {
+    fn schema_name() -> Cow<'static, str> {
+        "TileFormat".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Encoding of the tile data",
+            "type": "string",
+            "examples": ["png", "jpg", "webp", "avif", "pbf", "mvt", "mlt", "json"],
+        })
+    }
+}
+
+impl JsonSchema for RasterDemEncoding {
+    fn schema_name() -> Cow<'static, str> {
+        "RasterDemEncoding".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Encoding of the elevation in raster-dem tiles",
+            "type": "string",
+            "examples": ["mapbox", "terrarium", "custom"],
+        })
+    }
+}
+
+impl JsonSchema for GeometryType {
+    fn schema_name() -> Cow<'static, str> {
+        "GeometryType".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Geometry type of the features in a layer",
+            "type": "string",
+            "examples": ["Point", "LineString", "Polygon", "MultiPoint", "MultiLineString", "MultiPolygon"],
+        })
+    }
+}
+
+impl JsonSchema for Bounds {
+    fn schema_name() -> Cow<'static, str> {
+        "Bounds".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "The bounds as [left, bottom, right, top] in WGS 84 degrees",
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": 4,
+            "maxItems": 4,
+        })
+    }
+}
+
+impl JsonSchema for Center {
+    fn schema_name() -> Cow<'static, str> {
+        "Center".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "The default location as [longitude, latitude, zoom], with an optional zoom",
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": 2,
+            "maxItems": 3,
+        })
+    }
+}
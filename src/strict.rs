@@ -0,0 +1,228 @@
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::json_error::{from_str, from_value, JsonError};
+use crate::TileJSON;
+
+/// Keys of the `TileJSON` fields, used to suggest corrections for misspelled keys
+const TILEJSON_KEYS: &[&str] = &[
+    "tilejson",
+    "tiles",
+    "vector_layers",
+    "attribution",
+    "bounds",
+    "center",
+    "data",
+    "description",
+    "fillzoom",
+    "grids",
+    "legend",
+    "maxzoom",
+    "minzoom",
+    "name",
+    "scheme",
+    "template",
+    "version",
+    "tilestats",
+    "bearing",
+    "pitch",
+    "tileSize",
+    "tile_size",
+    "format",
+    "encoding",
+];
+
+/// Keys of the `VectorLayer` fields, used to suggest corrections for misspelled keys
+const VECTOR_LAYER_KEYS: &[&str] = &[
+    "id",
+    "fields",
+    "description",
+    "maxzoom",
+    "minzoom",
+    "geometry",
+];
+
+#[derive(Error, Debug)]
+pub enum StrictError {
+    #[error(transparent)]
+    Deserialize(#[from] JsonError),
+    #[error(
+        "Unknown key {path:?}{}",
+        suggestion.map(|v| format!(", did you mean {v:?}?")).unwrap_or_default()
+    )]
+    UnknownKey {
+        /// Path to the key, e.g. `max_zoom` or `vector_layers[0].colour`.
+        path: String,
+        /// A known key that differs only in case, `_`, or `-` characters.
+        suggestion: Option<&'static str>,
+    },
+}
+
+impl TileJSON {
+    /// Parse a JSON string as `TileJSON`, rejecting any unknown keys.
+    /// See [`TileJSON::from_value_strict`] for details.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid `TileJSON` document,
+    /// or if it contains a key that is not allowed.
+    ///
+    /// ```
+    /// # use tilejson::TileJSON;
+    /// let json = r#"{"tilejson": "3.0.0", "tiles": ["https://example.com/"], "max_zoom": 14}"#;
+    /// let err = TileJSON::from_str_strict(json, &[]).unwrap_err();
+    /// assert_eq!(err.to_string(), r#"Unknown key "max_zoom", did you mean "maxzoom"?"#);
+    /// ```
+    pub fn from_str_strict(s: &str, allowed: &[&str]) -> Result<TileJSON, StrictError> {
+        Self::from_value_strict(from_str(s)?, allowed)
+    }
+
+    /// Parse a JSON value as `TileJSON`, rejecting any key that would be stored
+    /// in [`TileJSON::other`] or [`VectorLayer::other`](crate::VectorLayer::other),
+    /// unless it is in the `allowed` list of known extension keys.
+    /// The same keys are allowed in the document and in its vector layers, use
+    /// [`ExtensionRegistry::parse_strict`](crate::ExtensionRegistry::parse_strict)
+    /// to allow them separately.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a valid `TileJSON` document,
+    /// or if it contains a key that is not allowed.
+    pub fn from_value_strict(value: Value, allowed: &[&str]) -> Result<TileJSON, StrictError> {
+        Self::from_value_strict_with(value, allowed, allowed)
+    }
+
+    /// Same as [`TileJSON::from_value_strict`], with separate lists of keys allowed
+    /// in the document and in its vector layers.
+    pub(crate) fn from_value_strict_with(
+        value: Value,
+        allowed: &[&str],
+        layer_allowed: &[&str],
+    ) -> Result<TileJSON, StrictError> {
+        let tilejson: TileJSON = from_value(value)?;
+        let unknown = tilejson
+            .find_unknown_keys(allowed, layer_allowed)
+            .into_iter()
+            .next();
+        match unknown {
+            Some(UnknownKey { path, key, known }) => {
+                let suggestion = suggest(key, known);
+                Err(StrictError::UnknownKey { path, suggestion })
+            }
+            None => Ok(tilejson),
+        }
+    }
+
+    /// Get the paths of all keys stored in [`TileJSON::other`] and
+    /// [`VectorLayer::other`](crate::VectorLayer::other) that are not in the `allowed` list.
+    /// Vector layer keys are reported as `vector_layers[<index>].<key>`.
+    ///
+    /// ```
+    /// # use tilejson::tilejson;
+    /// let mut tj = tilejson! { "https://example.com/".to_string() };
+    /// tj.other.insert("foo".to_string(), 1.into());
+    /// tj.other.insert("bar".to_string(), 2.into());
+    /// assert_eq!(tj.unknown_keys(&["foo"]), vec!["bar"]);
+    /// ```
+    #[must_use]
+    pub fn unknown_keys(&self, allowed: &[&str]) -> Vec<String> {
+        self.find_unknown_keys(allowed, allowed)
+            .into_iter()
+            .map(|v| v.path)
+            .collect()
+    }
+
+    fn find_unknown_keys(&self, allowed: &[&str], layer_allowed: &[&str]) -> Vec<UnknownKey<'_>> {
+        let mut result: Vec<UnknownKey> = self
+            .other
+            .keys()
+            .filter(|key| !allowed.contains(&key.as_str()))
+            .map(|key| UnknownKey {
+                path: key.clone(),
+                key,
+                known: TILEJSON_KEYS,
+            })
+            .collect();
+        for (idx, layer) in self.vector_layers.iter().flatten().enumerate() {
+            for key in layer
+                .other
+                .keys()
+                .filter(|key| !layer_allowed.contains(&key.as_str()))
+            {
+                result.push(UnknownKey {
+                    path: format!("vector_layers[{idx}].{key}"),
+                    key,
+                    known: VECTOR_LAYER_KEYS,
+                });
+            }
+        }
+        result
+    }
+}
+
+/// An unknown key, together with the known keys of the object it was found in
+struct UnknownKey<'a> {
+    path: String,
+    key: &'a str,
+    known: &'static [&'static str],
+}
+
+fn suggest(key: &str, known: &[&'static str]) -> Option<&'static str> {
+    let normalize = |v: &str| v.replace(['_', '-'], "").to_lowercase();
+    let key = normalize(key);
+    known.iter().copied().find(|v| normalize(v) == key)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_strict() {
+        let doc = json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/"],
+            "x-ext": 1,
+            "vector_layers": [{"id": "roads", "fields": {}, "Min-Zoom": 2}],
+        });
+        let err = TileJSON::from_value_strict(doc.clone(), &[]).unwrap_err();
+        assert!(matches!(
+            err,
+            StrictError::UnknownKey { ref path, suggestion: None } if path == "x-ext"
+        ));
+
+        let err = TileJSON::from_value_strict(doc.clone(), &["x-ext"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Unknown key "vector_layers[0].Min-Zoom", did you mean "minzoom"?"#
+        );
+
+        let tj = TileJSON::from_value_strict(doc, &["x-ext", "Min-Zoom"]).unwrap();
+        assert_eq!(tj.other["x-ext"], 1);
+    }
+
+    #[test]
+    fn test_strict_errors() {
+        assert!(matches!(
+            TileJSON::from_str_strict("{", &[]),
+            Err(StrictError::Deserialize(_))
+        ));
+        let doc = json!({"tilejson": "3.0.0", "tiles": ["https://example.com/"]});
+        assert!(TileJSON::from_value_strict(doc, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_strict_nesting() {
+        // A top-level key containing a dot is not mistaken for a vector layer key
+        let doc = json!({"tilejson": "3.0.0", "tiles": ["https://example.com/"], "x.minzoom": 1});
+        let err = TileJSON::from_value_strict(doc, &[]).unwrap_err();
+        assert_eq!(err.to_string(), r#"Unknown key "x.minzoom""#);
+
+        let doc = json!({"tilejson": "3.0.0", "tiles": ["https://example.com/"], "Max_Zoom": 1});
+        let err = TileJSON::from_value_strict(doc, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Unknown key "Max_Zoom", did you mean "maxzoom"?"#
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::zoom::MAX_ZOOM;
+use crate::TileJSON;
+
+/// A single violation of the `TileJSON` 3.0.0 document structure, part of a [`StructureError`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StructureViolation {
+    /// Path to the offending value, e.g. `vector_layers[0].fields.name`.
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for StructureViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// All violations of the document structure found by [`TileJSON::validate_structure`].
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+#[error(
+    "Document does not follow the TileJSON 3.0.0 structure: {}",
+    .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+)]
+pub struct StructureError(pub Vec<StructureViolation>);
+
+impl TileJSON {
+    /// Check the structure of a raw JSON document before parsing it as `TileJSON`.
+    ///
+    /// This ports every constraint of the
+    /// [`TileJSON` 3.0.0 schema](https://github.com/mapbox/tilejson-spec/blob/master/3.0.0/schema.json):
+    /// required keys, value types, version patterns, coordinate and zoom ranges, and the `scheme` values.
+    /// Array lengths are checked as well, as required by the spec.
+    /// Unlike parsing, this reports every violation, and it rejects values this crate accepts
+    /// as extensions of the spec, like a `center` without a zoom.
+    /// Unknown keys are allowed, just like in the schema.
+    ///
+    /// # Errors
+    /// Returns a [`StructureError`] with all violations found.
+    ///
+    /// ```
+    /// # use tilejson::TileJSON;
+    /// let doc = serde_json::json!({"tilejson": "3.0.0", "tiles": ["x"], "maxzoom": 31});
+    /// let err = TileJSON::validate_structure(&doc).unwrap_err();
+    /// assert_eq!(err.0.len(), 1);
+    /// assert_eq!(err.0[0].to_string(), "maxzoom: must be an integer between 0 and 30");
+    ///
+    /// let doc = serde_json::json!({"tilejson": "3.0.0", "tiles": ["x"], "center": [0, 0]});
+    /// assert!(TileJSON::validate_structure(&doc).is_err());
+    /// assert!(serde_json::from_value::<TileJSON>(doc).is_ok());
+    /// ```
+    pub fn validate_structure(value: &Value) -> Result<(), StructureError> {
+        let mut violations = Vec::new();
+        let mut fail = |path: &str, message: &str| {
+            violations.push(StructureViolation {
+                path: path.to_string(),
+                message: message.to_string(),
+            });
+        };
+
+        let Some(doc) = value.as_object() else {
+            fail("", "must be an object");
+            return Err(StructureError(violations));
+        };
+        for key in ["tilejson", "tiles"] {
+            if !doc.contains_key(key) {
+                fail(key, "is required");
+            }
+        }
+        for (key, value) in doc {
+            let valid = match key.as_str() {
+                "tilejson" => value.as_str().is_some_and(is_spec_version),
+                "version" => value.as_str().is_some_and(is_version),
+                "tiles" | "data" | "grids" => is_string_array(value),
+                "attribution" | "description" | "legend" | "name" | "template" => value.is_string(),
+                "scheme" => matches!(value.as_str(), Some("xyz" | "tms")),
+                "bounds" => matches!(
+                    value.as_array().map(Vec::as_slice),
+                    Some([left, bottom, right, top])
+                        if is_lon(left) && is_lat(bottom) && is_lon(right) && is_lat(top)
+                ),
+                "center" => matches!(
+                    value.as_array().map(Vec::as_slice),
+                    Some([lon, lat, zoom]) if is_lon(lon) && is_lat(lat) && is_zoom(zoom)
+                ),
+                "minzoom" | "maxzoom" | "fillzoom" => is_zoom(value),
+                "vector_layers" => {
+                    if let Some(layers) = value.as_array() {
+                        for (idx, layer) in layers.iter().enumerate() {
+                            check_layer(&format!("vector_layers[{idx}]"), layer, &mut fail);
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                }
+                _ => true,
+            };
+            if !valid {
+                fail(key, expected(key));
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(StructureError(violations))
+        }
+    }
+}
+
+fn check_layer(path: &str, layer: &Value, fail: &mut impl FnMut(&str, &str)) {
+    let Some(layer) = layer.as_object() else {
+        fail(path, "must be an object");
+        return;
+    };
+    for key in ["id", "fields"] {
+        if !layer.contains_key(key) {
+            fail(&format!("{path}.{key}"), "is required");
+        }
+    }
+    for (key, value) in layer {
+        let valid = match key.as_str() {
+            "id" | "description" => value.is_string(),
+            "minzoom" | "maxzoom" => is_zoom(value),
+            "fields" => value
+                .as_object()
+                .is_some_and(|v: &Map<String, Value>| v.values().all(Value::is_string)),
+            _ => true,
+        };
+        if !valid {
+            fail(&format!("{path}.{key}"), expected(key));
+        }
+    }
+}
+
+fn expected(key: &str) -> &'static str {
+    match key {
+        "tilejson" => "must be a TileJSON version like \"3.0.0\"",
+        "version" => "must be a version string like \"1.0.0\"",
+        "tiles" | "data" | "grids" => "must be an array of strings",
+        "scheme" => "must be \"xyz\" or \"tms\"",
+        "bounds" => "must be 4 numbers: left and right between -180 and 180, bottom and top between -90 and 90",
+        "center" => "must be 3 numbers: lon between -180 and 180, lat between -90 and 90, and an integer zoom between 0 and 30",
+        "minzoom" | "maxzoom" | "fillzoom" => "must be an integer between 0 and 30",
+        "vector_layers" => "must be an array of objects",
+        "fields" => "must be an object with string values",
+        _ => "must be a string",
+    }
+}
+
+fn is_zoom(value: &Value) -> bool {
+    value.as_u64().is_some_and(|v| v <= u64::from(MAX_ZOOM))
+}
+
+fn is_string_array(value: &Value) -> bool {
+    value
+        .as_array()
+        .is_some_and(|v| v.iter().all(Value::is_string))
+}
+
+fn is_lon(value: &Value) -> bool {
+    value
+        .as_f64()
+        .is_some_and(|v| (-180.0..=180.0).contains(&v))
+}
+
+fn is_lat(value: &Value) -> bool {
+    value.as_f64().is_some_and(|v| (-90.0..=90.0).contains(&v))
+}
+
+/// Match the `^[123]\.[012]\.[01]$` pattern of the schema
+fn is_spec_version(value: &str) -> bool {
+    matches!(
+        value.as_bytes(),
+        [b'1'..=b'3', b'.', b'0'..=b'2', b'.', b'0' | b'1']
+    )
+}
+
+/// Match the `^\d+\.\d+\.\d+\w?[\w\d]*$` pattern of the schema,
+/// i.e. three dot-separated numbers followed by optional word characters
+fn is_version(value: &str) -> bool {
+    let mut parts = value.splitn(3, '.');
+    let is_number = |v: &str| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit());
+    let (Some(major), Some(minor), Some(rest)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let patch = rest.bytes().take_while(u8::is_ascii_digit).count();
+    is_number(major)
+        && is_number(minor)
+        && patch > 0
+        && rest[patch..]
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let doc = json!({
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/{z}/{x}/{y}.pbf"],
+            "vector_layers": [{"id": "roads", "fields": {"name": "String"}, "maxzoom": 14, "x": 1}],
+            "bounds": [-180, -85, 180, 85.5],
+            "center": [0, 0, 2],
+            "version": "1.0.0beta1",
+            "scheme": "tms",
+            "x-custom": [1, 2],
+        });
+        assert_eq!(TileJSON::validate_structure(&doc), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let doc = json!({
+            "tilejson": "3.0",
+            "vector_layers": [{"id": 1, "fields": {"a": 2}}, {"fields": {}, "minzoom": -1}, "x"],
+            "center": [0, 0, 2.5],
+            "bounds": [1, 2, 3],
+            "name": null,
+        });
+        let err = TileJSON::validate_structure(&doc).unwrap_err();
+        let mut paths: Vec<_> = err.0.iter().map(|v| v.path.as_str()).collect();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            vec![
+                "bounds",
+                "center",
+                "name",
+                "tilejson",
+                "tiles",
+                "vector_layers[0].fields",
+                "vector_layers[0].id",
+                "vector_layers[1].id",
+                "vector_layers[1].minzoom",
+                "vector_layers[2]",
+            ]
+        );
+        assert!(TileJSON::validate_structure(&json!([])).is_err());
+    }
+
+    #[test]
+    fn test_center_zoom() {
+        let check = |center| {
+            TileJSON::validate_structure(
+                &json!({"tilejson": "3.0.0", "tiles": [], "center": center}),
+            )
+        };
+        assert_eq!(check(json!([0, 0, 30])), Ok(()));
+        assert!(check(json!([0, 0, -5])).is_err());
+        assert!(check(json!([0, 0, 100])).is_err());
+    }
+
+    /// Accepted and rejected values of every property constrained by the schema
+    #[test]
+    fn test_schema_cases() {
+        let check = |key: &str, value: Value| {
+            let mut doc = json!({"tilejson": "3.0.0", "tiles": []});
+            doc[key] = value;
+            TileJSON::validate_structure(&doc)
+        };
+        let cases = [
+            ("tilejson", json!("1.0.0"), true),
+            ("tilejson", json!("2.2.0"), true),
+            ("tilejson", json!("3.0.1"), true),
+            ("tilejson", json!("4.0.0"), false),
+            ("tilejson", json!("3.0.2"), false),
+            ("tilejson", json!("3.0"), false),
+            ("tilejson", json!("v3.0.0"), false),
+            ("tilejson", json!("3.0.0 "), false),
+            ("tilejson", json!(3), false),
+            ("tiles", json!(["a", "b"]), true),
+            ("tiles", json!("a"), false),
+            ("tiles", json!([1]), false),
+            ("vector_layers", json!([]), true),
+            ("vector_layers", json!({}), false),
+            ("attribution", json!("x"), true),
+            ("attribution", json!(["x"]), false),
+            ("bounds", json!([-180, -90, 180, 90]), true),
+            ("bounds", json!([-500, 0, 0, 10]), false),
+            ("bounds", json!([0, -91, 0, 10]), false),
+            ("bounds", json!([0, 0, 180.5, 10]), false),
+            ("bounds", json!([0, 0, 0, 90.1]), false),
+            ("bounds", json!([0, 0, "0", 10]), false),
+            ("center", json!([-180, 90, 0]), true),
+            ("center", json!([180.5, 0, 2]), false),
+            ("center", json!([0, -90.5, 2]), false),
+            ("center", json!([0, 0, 31]), false),
+            ("center", json!([0, 0, 2.5]), false),
+            ("data", json!(["a.geojson"]), true),
+            ("data", json!([{}]), false),
+            ("description", json!("x"), true),
+            ("description", json!(1), false),
+            ("fillzoom", json!(0), true),
+            ("fillzoom", json!(-1), false),
+            ("grids", json!(["a"]), true),
+            ("grids", json!([null]), false),
+            ("legend", json!("x"), true),
+            ("legend", json!(false), false),
+            ("maxzoom", json!(30), true),
+            ("maxzoom", json!(31), false),
+            ("minzoom", json!(0), true),
+            ("minzoom", json!(1.5), false),
+            ("name", json!("x"), true),
+            ("name", json!(null), false),
+            ("scheme", json!("xyz"), true),
+            ("scheme", json!("tms"), true),
+            ("scheme", json!("XYZ"), false),
+            ("scheme", json!("wmts"), false),
+            ("template", json!("x"), true),
+            ("template", json!({}), false),
+            ("version", json!("1.0.0"), true),
+            ("version", json!("10.20.30rc_1"), true),
+            ("version", json!("1.0"), false),
+            ("version", json!("1.0.x"), false),
+            ("version", json!("1..0.0"), false),
+            ("version", json!("1.0.0-beta"), false),
+            ("version", json!("v1.0.0"), false),
+            ("x-custom", json!(null), true),
+        ];
+        for (key, value, valid) in cases {
+            let result = check(key, value.clone());
+            assert_eq!(result.is_ok(), valid, "{key}: {value} gave {result:?}");
+            if let Err(err) = result {
+                assert_eq!(err.0.len(), 1);
+                assert_eq!(err.0[0].path, key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_layer_schema_cases() {
+        let check = |layer: Value| {
+            TileJSON::validate_structure(
+                &json!({"tilejson": "3.0.0", "tiles": [], "vector_layers": [layer]}),
+            )
+            .is_ok()
+        };
+        assert!(check(json!({"id": "a", "fields": {}})));
+        assert!(check(
+            json!({"id": "a", "fields": {"b": "Number"}, "description": "x", "minzoom": 0, "maxzoom": 30})
+        ));
+        assert!(!check(json!({"fields": {}})));
+        assert!(!check(json!({"id": "a"})));
+        assert!(!check(json!({"id": "a", "fields": {"b": 1}})));
+        assert!(!check(json!({"id": "a", "fields": [], "description": 1})));
+        assert!(!check(json!({"id": "a", "fields": {}, "maxzoom": 1.5})));
+    }
+}
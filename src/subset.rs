@@ -0,0 +1,283 @@
+use thiserror::Error;
+
+use crate::bounds::Bounds;
+use crate::center::Center;
+use crate::zoom::Zoom;
+use crate::TileJSON;
+
+/// Restrictions used by [`TileJSON::subset`] to derive a child tileset,
+/// e.g. a regional extract of a global tileset.
+///
+/// Each restriction is optional, and `None` keeps the corresponding value of the original tileset.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Subset {
+    /// Restrict the tileset to this area.
+    pub bounds: Option<Bounds>,
+    /// Restrict the tileset to zoom levels at or above this value.
+    pub minzoom: Option<Zoom>,
+    /// Restrict the tileset to zoom levels at or below this value.
+    pub maxzoom: Option<Zoom>,
+    /// Keep only the vector layers with these ids.
+    pub layers: Option<Vec<String>>,
+}
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum SubsetError {
+    #[error("Subset bounds {0} do not overlap with the tileset bounds {1}")]
+    NoOverlap(Bounds, Bounds),
+    #[error("Subset zoom range {0}..={1} does not overlap with the tileset zoom range {2}..={3}")]
+    NoZoomOverlap(u8, u8, u8, u8),
+    #[error("Vector layer {0} does not exist in the tileset")]
+    UnknownLayer(String),
+}
+
+impl TileJSON {
+    /// Derive a child tileset restricted to a sub-area, a narrower zoom range,
+    /// and/or a subset of the vector layers.
+    ///
+    /// * `bounds` are set to the intersection of the tileset and the subset bounds.
+    /// * `minzoom` and `maxzoom` are narrowed to the subset zoom range,
+    ///   and `fillzoom` is clamped to the resulting range.
+    /// * `center` is moved inside the new bounds and zoom range.
+    /// * `vector_layers` not listed in the subset, or with zoom ranges outside of
+    ///   the new zoom range are removed, and the zoom ranges of the remaining layers are clamped.
+    ///   The `tilestats` entries of the removed layers are removed as well.
+    ///
+    /// All other values are kept as is.
+    ///
+    /// # Errors
+    /// Returns an error if the subset bounds or zoom range do not overlap with the tileset,
+    /// or if the subset lists a vector layer that does not exist.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Bounds, Center, Subset, Zoom};
+    /// let tj = tilejson! {
+    ///     "https://example.com/{z}/{x}/{y}.pbf".to_string(),
+    ///     center: Center::new(0., 0., 2),
+    ///     maxzoom: 14,
+    /// };
+    /// let extract = tj.subset(&Subset {
+    ///     bounds: Some(Bounds::new(5., 45., 10., 48.)),
+    ///     minzoom: Zoom::new(4).ok(),
+    ///     ..Default::default()
+    /// }).unwrap();
+    /// assert_eq!(extract.bounds, Some(Bounds::new(5., 45., 10., 48.)));
+    /// assert_eq!(extract.center, Some(Center::new(5., 45., 4)));
+    /// assert_eq!(extract.zoom_range().unwrap().to_string(), "4..=14");
+    /// ```
+    pub fn subset(&self, subset: &Subset) -> Result<TileJSON, SubsetError> {
+        let mut result = self.clone();
+
+        if let Some(bounds) = subset.bounds {
+            let current = self.bounds.unwrap_or_default();
+            let bounds = current
+                .intersection(bounds)
+                .ok_or(SubsetError::NoOverlap(bounds, current))?;
+            result.bounds = Some(bounds);
+        }
+
+        let min_zoom = self.minzoom.unwrap_or(Zoom::MIN);
+        let max_zoom = self.maxzoom.unwrap_or(Zoom::MAX);
+        let new_min = subset.minzoom.map_or(min_zoom, |v| v.max(min_zoom));
+        let new_max = subset.maxzoom.map_or(max_zoom, |v| v.min(max_zoom));
+        if new_min > new_max {
+            return Err(SubsetError::NoZoomOverlap(
+                subset.minzoom.unwrap_or(Zoom::MIN).get(),
+                subset.maxzoom.unwrap_or(Zoom::MAX).get(),
+                min_zoom.get(),
+                max_zoom.get(),
+            ));
+        }
+        if subset.minzoom.is_some() {
+            result.minzoom = Some(new_min);
+        }
+        if subset.maxzoom.is_some() {
+            result.maxzoom = Some(new_max);
+        }
+        result.fillzoom = result.fillzoom.map(|v| v.clamp(new_min, new_max));
+
+        if let Some(center) = result.center {
+            result.center = Some(Center {
+                zoom: center
+                    .zoom
+                    .map(|v| v.clamp(f64::from(new_min), f64::from(new_max))),
+                ..center
+            });
+            if let Some(bounds) = result.bounds {
+                result.center = result.center.map(|v| clamp_center(v, bounds));
+            }
+        }
+
+        if let Some(ids) = &subset.layers {
+            let exists = |id: &String| {
+                self.vector_layers.iter().flatten().any(|v| &v.id == id)
+                    || self.layer_stats(id).is_some()
+            };
+            if let Some(id) = ids.iter().find(|id| !exists(id)) {
+                return Err(SubsetError::UnknownLayer(id.clone()));
+            }
+        }
+        let is_listed = |id: &String| subset.layers.as_ref().map_or(true, |ids| ids.contains(id));
+        let mut removed = Vec::new();
+        if let Some(layers) = &mut result.vector_layers {
+            layers.retain(|v| {
+                let keep = is_listed(&v.id)
+                    && v.minzoom.map_or(true, |z| z <= new_max)
+                    && v.maxzoom.map_or(true, |z| z >= new_min);
+                if !keep {
+                    removed.push(v.id.clone());
+                }
+                keep
+            });
+            for layer in layers.iter_mut() {
+                layer.minzoom = layer.minzoom.map(|v| v.max(new_min));
+                layer.maxzoom = layer.maxzoom.map(|v| v.min(new_max));
+            }
+        }
+        if let Some(tilestats) = &mut result.tilestats {
+            tilestats
+                .layers
+                .retain(|v| is_listed(&v.layer) && !removed.contains(&v.layer));
+            tilestats.layer_count = tilestats.layers.len() as u64;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Move the center to the nearest point within the bounds
+fn clamp_center(center: Center, bounds: Bounds) -> Center {
+    if bounds.contains(center.longitude, center.latitude) {
+        return center;
+    }
+    let latitude = center.latitude.clamp(bounds.bottom, bounds.top);
+    let longitude = if bounds.contains(center.longitude, latitude) {
+        center.longitude
+    } else {
+        // Pick the closest edge, taking the antimeridian into account
+        let dist = |v: f64| {
+            let d = (center.longitude - v).rem_euclid(360.);
+            d.min(360. - d)
+        };
+        if dist(bounds.left) <= dist(bounds.right) {
+            bounds.left
+        } else {
+            bounds.right
+        }
+    };
+    Center {
+        longitude,
+        latitude,
+        ..center
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{tilejson, Tilestats, VectorLayer};
+
+    fn zoom(v: u8) -> Option<Zoom> {
+        Zoom::new(v).ok()
+    }
+
+    fn source() -> TileJSON {
+        let layer = |id: &str, minzoom, maxzoom| {
+            let mut layer = VectorLayer::new(id.to_string(), BTreeMap::new());
+            layer.minzoom = minzoom;
+            layer.maxzoom = maxzoom;
+            layer
+        };
+        let mut tj = tilejson! {
+            "https://example.com/{z}/{x}/{y}.pbf".to_string(),
+            name: "world".to_string(),
+            bounds: Bounds::new(-180., -85., 180., 85.),
+            center: Center::new(-100., 40., 3),
+            minzoom: 0,
+            maxzoom: 14,
+            fillzoom: 12,
+            vector_layers: vec![
+                layer("countries", zoom(0), zoom(6)),
+                layer("roads", zoom(5), zoom(14)),
+                layer("buildings", zoom(13), None),
+            ],
+        };
+        tj.tilestats = Some(serde_json::from_str(
+            r#"{"layerCount": 2, "layers": [
+                {"layer": "countries", "count": 1, "geometry": "Polygon", "attributeCount": 0, "attributes": []},
+                {"layer": "roads", "count": 1, "geometry": "LineString", "attributeCount": 0, "attributes": []}
+            ]}"#,
+        ).unwrap());
+        tj
+    }
+
+    #[test]
+    fn test_subset() {
+        let tj = source()
+            .subset(&Subset {
+                bounds: Some(Bounds::new(5., 45., 10., 48.)),
+                minzoom: zoom(4),
+                maxzoom: zoom(10),
+                layers: None,
+            })
+            .unwrap();
+        assert_eq!(tj.name.as_deref(), Some("world"));
+        assert_eq!(tj.bounds, Some(Bounds::new(5., 45., 10., 48.)));
+        assert_eq!(tj.center, Some(Center::new(5., 45., 4)));
+        assert_eq!(
+            (tj.minzoom, tj.maxzoom, tj.fillzoom),
+            (zoom(4), zoom(10), zoom(10))
+        );
+
+        let layers = tj.vector_layers.unwrap();
+        let ids: Vec<_> = layers.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, vec!["countries", "roads"]);
+        assert_eq!((layers[0].minzoom, layers[0].maxzoom), (zoom(4), zoom(6)));
+        assert_eq!((layers[1].minzoom, layers[1].maxzoom), (zoom(5), zoom(10)));
+    }
+
+    #[test]
+    fn test_subset_layers() {
+        let tj = source()
+            .subset(&Subset {
+                layers: Some(vec!["roads".to_string()]),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(tj.vector_layers.unwrap().len(), 1);
+        let stats: &Tilestats = tj.tilestats.as_ref().unwrap();
+        assert_eq!(stats.layer_count, 1);
+        assert_eq!(stats.layers[0].layer, "roads");
+        // Everything else is intact
+        assert_eq!(tj.bounds, source().bounds);
+        assert_eq!(tj.center, source().center);
+    }
+
+    #[test]
+    fn test_subset_errors() {
+        let err = |subset| source().subset(&subset).unwrap_err();
+        assert_eq!(
+            err(Subset {
+                minzoom: zoom(15),
+                ..Default::default()
+            }),
+            SubsetError::NoZoomOverlap(15, 30, 0, 14)
+        );
+        assert_eq!(
+            err(Subset {
+                layers: Some(vec!["water".to_string()]),
+                ..Default::default()
+            }),
+            SubsetError::UnknownLayer("water".to_string())
+        );
+        assert!(matches!(
+            err(Subset {
+                bounds: Some(Bounds::new(0., 86., 1., 87.)),
+                ..Default::default()
+            }),
+            SubsetError::NoOverlap(..)
+        ));
+    }
+}
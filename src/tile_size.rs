@@ -0,0 +1,248 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::center::Center;
+use crate::zoom::{Zoom, MAX_ZOOM};
+use crate::TileJSON;
+
+/// Size of the square tiles in pixels, as stored in the `tileSize` extension.
+///
+/// Zoom levels depend on the tile size: a 512-pixel tile at zoom `z` covers the same area
+/// as four 256-pixel tiles at zoom `z + 1`. Clients that assume 256-pixel tiles, like Leaflet,
+/// must add one to the zoom levels of a 512-pixel tileset to show the same map scale.
+///
+/// Any positive size is accepted when parsing, so that unusual documents can still be read.
+/// [`TileJSON::validate`] reports sizes that are not [standard](TileSize::is_standard).
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct TileSize(u32);
+
+impl TileSize {
+    /// The traditional 256-pixel tile size, assumed if `tileSize` is not set.
+    pub const PX_256: Self = Self(256);
+    /// The 512-pixel tile size, commonly used by vector tiles and high-resolution raster tiles.
+    pub const PX_512: Self = Self(512);
+
+    /// Create a tile size of a positive number of pixels.
+    ///
+    /// # Errors
+    /// Returns [`TileSizeError::Invalid`] if the size is zero.
+    pub fn new(size: u32) -> Result<Self, TileSizeError> {
+        if size > 0 {
+            Ok(Self(size))
+        } else {
+            Err(TileSizeError::Invalid(size))
+        }
+    }
+
+    /// Get the tile size in pixels.
+    #[must_use]
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Check if the size is a power of two between 64 and 4096 pixels, as supported by clients.
+    ///
+    /// ```
+    /// # use tilejson::TileSize;
+    /// assert!(TileSize::PX_512.is_standard());
+    /// assert!(!TileSize::new(300).unwrap().is_standard());
+    /// ```
+    #[must_use]
+    pub fn is_standard(self) -> bool {
+        self.0.is_power_of_two() && (64..=4096).contains(&self.0)
+    }
+
+    /// Number of zoom levels to add to a zoom of this tile size
+    /// to get the zoom with the same map scale for the other tile size.
+    /// Unless both sizes are [standard](TileSize::is_standard), the offset is rounded
+    /// to the nearest integer; see [`TileSize::convert_zoom`] for the exact value.
+    ///
+    /// ```
+    /// # use tilejson::TileSize;
+    /// assert_eq!(TileSize::PX_512.zoom_offset(TileSize::PX_256), 1);
+    /// assert_eq!(TileSize::PX_256.zoom_offset(TileSize::PX_512), -1);
+    /// assert_eq!(TileSize::PX_256.zoom_offset(TileSize::PX_256), 0);
+    /// ```
+    #[must_use]
+    pub fn zoom_offset(self, other: Self) -> i8 {
+        // Both sizes are between 1 and 2^32, so the offset is between -32 and 32
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.log2_ratio(other).round() as i8
+        }
+    }
+
+    /// Convert a possibly fractional zoom of this tile size to the other tile size.
+    ///
+    /// ```
+    /// # use tilejson::TileSize;
+    /// assert_eq!(TileSize::PX_512.convert_zoom(2.5, TileSize::PX_256), 3.5);
+    /// ```
+    #[must_use]
+    pub fn convert_zoom(self, zoom: f64, other: Self) -> f64 {
+        zoom + self.log2_ratio(other)
+    }
+
+    fn log2_ratio(self, other: Self) -> f64 {
+        (f64::from(self.0) / f64::from(other.0)).log2()
+    }
+}
+
+impl Default for TileSize {
+    fn default() -> Self {
+        Self::PX_256
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum TileSizeError {
+    #[error("Tile size {0} must be a positive number of pixels")]
+    Invalid(u32),
+}
+
+impl TryFrom<u32> for TileSize {
+    type Error = TileSizeError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<TileSize> for u32 {
+    fn from(value: TileSize) -> Self {
+        value.0
+    }
+}
+
+impl Display for TileSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TileJSON {
+    /// Get a copy of this document with the zoom levels adjusted for a client
+    /// that assumes the given tile size, e.g. a Leaflet map showing 512-pixel tiles.
+    ///
+    /// The `minzoom`, `maxzoom`, `fillzoom`, `center` zoom, and vector layer zoom levels
+    /// are shifted by the [zoom offset](TileSize::zoom_offset) from the `tile_size` of this document
+    /// (256 pixels if not set) to the client tile size, saturating at 0 and 30.
+    /// A missing `minzoom` or `maxzoom` is set to its spec default before shifting,
+    /// because the client would otherwise apply the default to the shifted range.
+    /// The `tile_size` itself is kept, because the tiles do not change.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, Center, TileSize};
+    /// let tj = tilejson! {
+    ///     "https://example.com/{z}/{x}/{y}.pbf".to_string(),
+    ///     tile_size: TileSize::PX_512,
+    ///     center: Center::new(0., 0., 2),
+    ///     minzoom: 0,
+    ///     maxzoom: 14,
+    /// };
+    /// let leaflet = tj.zooms_for_tile_size(TileSize::PX_256);
+    /// assert_eq!(leaflet.zoom_range().unwrap().to_string(), "1..=15");
+    /// assert_eq!(leaflet.center, Some(Center::new(0., 0., 3)));
+    /// ```
+    #[must_use]
+    pub fn zooms_for_tile_size(&self, client_tile_size: TileSize) -> TileJSON {
+        let offset = self
+            .tile_size
+            .unwrap_or_default()
+            .zoom_offset(client_tile_size);
+        let mut result = self.clone();
+        if offset == 0 {
+            return result;
+        }
+        result.minzoom = Some(result.minzoom.unwrap_or(Zoom::MIN));
+        result.maxzoom = Some(result.maxzoom.unwrap_or(Zoom::MAX));
+        for zoom in [
+            &mut result.minzoom,
+            &mut result.maxzoom,
+            &mut result.fillzoom,
+        ] {
+            *zoom = zoom.map(|v| v.saturating_add_signed(offset));
+        }
+        result.center = result.center.map(|v| Center {
+            zoom: v
+                .zoom
+                .map(|z| (z + f64::from(offset)).clamp(0.0, f64::from(MAX_ZOOM))),
+            ..v
+        });
+        for layer in result.vector_layers.iter_mut().flatten() {
+            layer.minzoom = layer.minzoom.map(|v| v.saturating_add_signed(offset));
+            layer.maxzoom = layer.maxzoom.map(|v| v.saturating_add_signed(offset));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{tilejson, VectorLayer};
+
+    #[test]
+    fn test_serde() {
+        let tj: TileJSON =
+            serde_json::from_str(r#"{"tilejson":"3.0.0","tiles":["x"],"tile_size":512}"#).unwrap();
+        assert_eq!(tj.tile_size, Some(TileSize::PX_512));
+        assert_eq!(
+            serde_json::to_string(&tj).unwrap(),
+            r#"{"tilejson":"3.0.0","tiles":["x"],"tileSize":512}"#
+        );
+        let tj: TileJSON =
+            serde_json::from_str(r#"{"tilejson":"3.0.0","tiles":["x"],"tileSize":300}"#).unwrap();
+        assert_eq!(tj.tile_size, TileSize::new(300).ok());
+        assert!(tj.other.is_empty());
+        assert!(serde_json::from_str::<TileJSON>(
+            r#"{"tilejson":"3.0.0","tiles":["x"],"tileSize":0}"#
+        )
+        .is_err());
+        assert_eq!(
+            TileSize::new(1024).unwrap().zoom_offset(TileSize::PX_256),
+            2
+        );
+        assert_eq!(TileSize::new(300).unwrap().zoom_offset(TileSize::PX_256), 0);
+        assert_eq!(TileSize::new(0), Err(TileSizeError::Invalid(0)));
+    }
+
+    #[test]
+    fn test_zooms_for_tile_size() {
+        let mut layer = VectorLayer::new("a".to_string(), BTreeMap::new());
+        layer.minzoom = Zoom::new(0).ok();
+        layer.maxzoom = Zoom::new(30).ok();
+        let tj = tilejson! {
+            "https://example.com/".to_string(),
+            center: Center::from((0., 0., 2.5)),
+            minzoom: 1,
+            fillzoom: 10,
+            vector_layers: vec![layer],
+        };
+        let adjusted = tj.zooms_for_tile_size(TileSize::PX_512);
+        assert_eq!(adjusted.minzoom, Zoom::new(0).ok());
+        assert_eq!(adjusted.maxzoom, Zoom::new(29).ok());
+        assert_eq!(adjusted.fillzoom, Zoom::new(9).ok());
+        assert_eq!(adjusted.center, Some(Center::from((0., 0., 1.5))));
+        let layer = &adjusted.vector_layers.as_ref().unwrap()[0];
+        assert_eq!(
+            (layer.minzoom, layer.maxzoom),
+            (Zoom::new(0).ok(), Zoom::new(29).ok())
+        );
+
+        assert_eq!(tj.zooms_for_tile_size(TileSize::PX_256), tj);
+
+        let tj = tilejson! {
+            "https://example.com/".to_string(),
+            tile_size: TileSize::PX_512,
+            maxzoom: 14,
+        };
+        let adjusted = tj.zooms_for_tile_size(TileSize::PX_256);
+        assert_eq!(adjusted.zoom_range().unwrap().to_string(), "1..=15");
+    }
+}
@@ -5,12 +5,18 @@ use serde_json::Value;
 
 use crate::bounds::Bounds;
 use crate::center::Center;
-use crate::vector_layer::VectorLayer;
+use crate::format::{RasterDemEncoding, TileFormat};
+use crate::tile_size::TileSize;
+use crate::tilestats::{GeometryType, Tilestats, TilestatsLayer};
+use crate::vector_layer::{RenderType, VectorLayer};
+use crate::zoom::Zoom;
 
 /// `TileJSON` struct represents tilejson-spec metadata as specified by
 /// <https://github.com/mapbox/tilejson-spec> (version 3.0.0)
 /// Some descriptions were copied verbatim from the spec per CC-BY 3.0 license.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TileJSON {
     /// A semver.org style version number as a string.
     /// Describes the version of the `TileJSON` spec that is implemented by this JSON object.
@@ -118,7 +124,7 @@ pub struct TileJSON {
     /// OPTIONAL. Integer. Default: null.
     /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#39-fillzoom>
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fillzoom: Option<u8>,
+    pub fillzoom: Option<Zoom>,
 
     /// An array of interactivity endpoints.
     ///
@@ -154,7 +160,7 @@ pub struct TileJSON {
     /// OPTIONAL. Integer. Default: 30.
     /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#312-maxzoom>
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maxzoom: Option<u8>,
+    pub maxzoom: Option<Zoom>,
 
     /// An integer specifying the minimum zoom level.
     ///
@@ -162,7 +168,7 @@ pub struct TileJSON {
     /// OPTIONAL. Integer. Default: 0.
     /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#313-minzoom>
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minzoom: Option<u8>,
+    pub minzoom: Option<Zoom>,
 
     /// A name describing the tileset.
     ///
@@ -202,6 +208,69 @@ pub struct TileJSON {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 
+    /// Statistics of the layers and their attributes, as generated by tippecanoe or mapbox-geostats.
+    ///
+    /// Feature counts, geometry types, and samples of attribute values let styling tools
+    /// suggest value ranges and color ramps without downloading any tiles.
+    /// See [`Tilestats`] for the format details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tilestats: Option<Tilestats>,
+
+    /// The initial rotation of the map in degrees, counter-clockwise from north.
+    ///
+    /// A bearing of 90 puts east at the top of the screen. Mirrors the root `bearing`
+    /// of a `MapLibre` style, which viewers use to open the map at the same angle.
+    /// OPTIONAL. Number. Default: 0.
+    /// See [`TileJSON::camera`] to get it together with the `center`.
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bearing: Option<f64>,
+
+    /// The initial tilt of the map in degrees, away from the plane of the screen.
+    ///
+    /// 0 looks straight down, and higher values show the map in perspective, towards the horizon.
+    /// Viewers without 3D support ignore it.
+    /// OPTIONAL. Number. Default: 0.
+    /// See [`TileJSON::camera`] to get it together with the `center`.
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pitch: Option<f64>,
+
+    /// The width and height of the square tiles in pixels, usually 256 or 512.
+    ///
+    /// `MapLibre` and Mapbox raster sources read it to pick the zoom level of each tile,
+    /// and assume 256 without it, so 512-pixel tiles would be shown one zoom level off.
+    /// Written as `tileSize`, and `tile_size` is also accepted when reading.
+    /// OPTIONAL. Integer. Default: 256.
+    #[serde(
+        rename = "tileSize",
+        alias = "tile_size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tile_size: Option<TileSize>,
+
+    /// The file format of the tiles, e.g. `png` or `pbf`.
+    ///
+    /// Copied by tile servers from the `format` of `MBTiles` and `PMTiles` metadata, it lets
+    /// clients choose between a raster and a vector source without fetching a tile.
+    /// See [`TileJSON::tile_format`] to infer it from the `tiles` URLs if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<TileFormat>,
+
+    /// How the elevation is packed into the red, green, and blue channels of `raster-dem` tiles.
+    ///
+    /// Used by terrain and hillshade layers, and ignored for any other kind of tiles.
+    /// OPTIONAL. String. Default: `mapbox`.
+    /// See [`RasterDemEncoding::elevation`] to decode a pixel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<RasterDemEncoding>,
+
     /// Any unrecognized fields will be stored here
     #[serde(flatten)]
     pub other: BTreeMap<String, Value>,
@@ -212,10 +281,43 @@ impl TileJSON {
     pub fn set_missing_defaults(&mut self) {
         self.version.get_or_insert_with(|| "1.0.0".to_string());
         self.scheme.get_or_insert_with(|| "xyz".to_string());
-        self.minzoom.get_or_insert(0);
-        self.maxzoom.get_or_insert(30);
+        self.minzoom.get_or_insert(Zoom::MIN);
+        self.maxzoom.get_or_insert(Zoom::MAX);
         self.bounds.get_or_insert_with(Bounds::default);
     }
+
+    /// Get the `tilestats` entry of the given vector layer, if available.
+    #[must_use]
+    pub fn layer_stats(&self, layer_id: &str) -> Option<&TilestatsLayer> {
+        self.tilestats.as_ref()?.layer(layer_id)
+    }
+
+    /// Get the geometry type of the given vector layer.
+    ///
+    /// Uses the [`VectorLayer::geometry`] value if known,
+    /// otherwise falls back to the `tilestats` entry of the layer.
+    #[must_use]
+    pub fn layer_geometry(&self, layer_id: &str) -> Option<GeometryType> {
+        self.vector_layers
+            .iter()
+            .flatten()
+            .find(|v| v.id == layer_id)
+            .and_then(|v| v.geometry.clone())
+            .filter(GeometryType::is_known)
+            .or_else(|| self.layer_stats(layer_id).map(|v| v.geometry.clone()))
+    }
+
+    /// Pick a render type suitable for the given vector layer, based on its [geometry](Self::layer_geometry).
+    #[must_use]
+    pub fn layer_render_type(&self, layer_id: &str) -> Option<RenderType> {
+        self.layer_geometry(layer_id)?.render_type()
+    }
+
+    /// Convert the document into a JSON value.
+    pub(crate) fn to_json_value(&self) -> Value {
+        // All types of the document serialize without errors, and all map keys are strings
+        serde_json::to_value(self).expect("TileJSON is always convertible to a JSON value")
+    }
 }
 
 /// Use this macro to create a `TileJSON` struct with optional values.
@@ -233,8 +335,8 @@ impl TileJSON {
 /// // With optional values
 /// let tj = tilejson! { "https://example.com/".to_string(), minzoom: 1, maxzoom: 2 };
 /// assert_eq!(tj.tiles[0], "https://example.com/");
-/// assert_eq!(tj.minzoom, Some(1));
-/// assert_eq!(tj.maxzoom, Some(2));
+/// assert_eq!(tj.minzoom.unwrap(), 1);
+/// assert_eq!(tj.maxzoom.unwrap(), 2);
 /// ```
 ///
 /// ### With multiple tile sources and an optional version
@@ -253,19 +355,26 @@ impl TileJSON {
 /// let tj = tilejson! { tiles: vec!["https://example.com/".to_string()], minzoom: 5 };
 /// assert_eq!(tj.tiles[0], "https://example.com/");
 /// assert_eq!(tj.tilejson, "3.0.0");
-/// assert_eq!(tj.minzoom, Some(5));
+/// assert_eq!(tj.minzoom.unwrap(), 5);
 ///
 /// // version and optional values together
 /// let tj = tilejson! { tilejson: "2.2.0".to_string(), tiles: vec!["https://example.com/".to_string()], minzoom: 5 };
 /// assert_eq!(tj.tiles[0], "https://example.com/");
 /// assert_eq!(tj.tilejson, "2.2.0");
-/// assert_eq!(tj.minzoom, Some(5));
+/// assert_eq!(tj.minzoom.unwrap(), 5);
+/// ```
+///
+/// ### Zoom levels
+/// Zoom values are converted to [`Zoom`](crate::Zoom), so the macro panics if a zoom is out of range.
+/// ```should_panic
+/// # use crate::tilejson::tilejson;
+/// let tj = tilejson! { "https://example.com/".to_string(), maxzoom: 200 };
 /// ```
 #[macro_export]
 macro_rules! tilejson {
     ( tilejson: $ver:expr, tiles: $sources:expr $(, $tag:tt : $val:expr)* $(,)? ) => {
         $crate::TileJSON {
-            $( $tag: Some($val), )*
+            $( $tag: Some($crate::__tilejson_value!($tag, $val)), )*
             ..$crate::TileJSON {
                 tilejson: $ver,
                 tiles: $sources,
@@ -284,6 +393,12 @@ macro_rules! tilejson {
                 scheme: None,
                 template: None,
                 version: None,
+                tilestats: None,
+                bearing: None,
+                pitch: None,
+                tile_size: None,
+                format: None,
+                encoding: None,
                 other: Default::default(),
             }
         }
@@ -301,6 +416,31 @@ macro_rules! tilejson {
     };
 }
 
+/// Convert a value of the [`tilejson!`] macro to the type of its field.
+/// Zoom levels are checked with [`Zoom::new`](crate::Zoom::new), other values are used as is.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tilejson_value {
+    (@zoom $tag:tt, $val:expr) => {
+        match $crate::Zoom::try_from($val) {
+            Ok(v) => v,
+            Err(e) => panic!("Invalid {} value: {}", stringify!($tag), e),
+        }
+    };
+    (minzoom, $val:expr) => {
+        $crate::__tilejson_value!(@zoom minzoom, $val)
+    };
+    (maxzoom, $val:expr) => {
+        $crate::__tilejson_value!(@zoom maxzoom, $val)
+    };
+    (fillzoom, $val:expr) => {
+        $crate::__tilejson_value!(@zoom fillzoom, $val)
+    };
+    ($tag:tt, $val:expr) => {
+        $val
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,6 +521,43 @@ mod tests {
         assert_eq!(tilejson, expected);
     }
 
+    #[test]
+    fn test_tilestats() {
+        let tilejson_str = r#"{
+        "tilejson": "3.0.0",
+        "tiles": ["http://localhost:8888/foo/{z}/{x}/{y}.pbf"],
+        "vector_layers": [{"id": "pois", "fields": {"rank": "Number"}}],
+        "tilestats": {
+            "layerCount": 1,
+            "layers": [{
+                "layer": "pois",
+                "count": 10,
+                "geometry": "Point",
+                "attributeCount": 1,
+                "attributes": [
+                    {"attribute": "rank", "count": 2, "type": "number", "values": [1, 2], "min": 1, "max": 2}
+                ]
+            }]
+        }
+    }"#;
+
+        let tilejson: TileJSON = serde_json::from_str(tilejson_str).unwrap();
+        assert!(tilejson.other.is_empty());
+        let stats = tilejson.layer_stats("pois").unwrap();
+        assert_eq!(stats.count, 10);
+        assert_eq!(stats.attribute("rank").unwrap().max, Some(2.0));
+
+        let vl = &tilejson.vector_layers.as_ref().unwrap()[0];
+        let stats = vl.stats(tilejson.tilestats.as_ref().unwrap()).unwrap();
+        assert_eq!(stats.layer, "pois");
+
+        // Layer geometry falls back to tilestats
+        assert_eq!(vl.geometry, None);
+        assert_eq!(tilejson.layer_geometry("pois"), Some(GeometryType::Point));
+        assert_eq!(tilejson.layer_render_type("pois"), Some(RenderType::Circle));
+        assert_eq!(tilejson.layer_geometry("roads"), None);
+    }
+
     #[test]
     fn test_writing() {
         let source = "http://localhost:8888/foo/{z}/{x}/{y}.png";
@@ -412,6 +589,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_macro_values() {
+        let tj = tilejson! {
+            "https://example.com/".to_string(),
+            minzoom: 1,
+            maxzoom: Zoom::MAX,
+            tile_size: TileSize::PX_512,
+        };
+        assert_eq!(tj.minzoom, Zoom::new(1).ok());
+        assert_eq!(tj.maxzoom, Some(Zoom::MAX));
+        assert_eq!(tj.tile_size, Some(TileSize::PX_512));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid fillzoom value: Zoom level 31 must be between 0 and 30")]
+    fn test_macro_bad_zoom() {
+        let _ = tilejson! { "https://example.com/".to_string(), fillzoom: 31 };
+    }
+
     fn parse(json_str: &str) -> serde_json::Result<TileJSON> {
         serde_json::from_str(json_str)
     }
@@ -419,10 +615,97 @@ mod tests {
     #[test]
     fn test_bad_json() {
         parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "center":[]}"#).unwrap_err();
-        parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "center":[1,2]}"#).unwrap_err();
+        parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "center":[1]}"#).unwrap_err();
+        parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "center":[1,2,"3"]}"#).unwrap_err();
         parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "center":[1,2,3,4]}"#).unwrap_err();
         parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "bounds":[]}"#).unwrap_err();
         parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "bounds":[1,2,3]}"#).unwrap_err();
         parse(r#"{"tilejson":"3.0.0", "tiles":["x"], "bounds":[1,2,3,4,5]}"#).unwrap_err();
     }
+
+    fn full_document() -> TileJSON {
+        parse(
+            r#"{
+            "tilejson": "3.0.0",
+            "tiles": ["https://example.com/{z}/{x}/{y}.pbf"],
+            "vector_layers": [
+                {"id": "roads", "fields": {"name": "String"}, "minzoom": 2, "maxzoom": 14, "geometry": "LineString", "x-color": "red"}
+            ],
+            "attribution": "OSM",
+            "bounds": [-180, -85.05, 180, 85.05],
+            "center": [10.5, -20, 4.5],
+            "fillzoom": 12,
+            "maxzoom": 14,
+            "minzoom": 0,
+            "name": "roads",
+            "tilestats": {"layerCount": 1, "layers": [{"layer": "roads", "count": 3, "geometry": "LineString", "attributeCount": 1,
+                "attributes": [{"attribute": "lanes", "count": 2, "type": "number", "values": [1, 2.5], "min": 1, "max": 2.5}]}]},
+            "bearing": -15,
+            "pitch": 30.5,
+            "tileSize": 512,
+            "format": "pbf",
+            "x-int": 7,
+            "x-float": -1.5,
+            "x-nested": {"a": [1, "b", true]}
+        }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let tj = full_document();
+        let yaml = serde_yaml::to_string(&tj).unwrap();
+        assert_eq!(serde_yaml::from_str::<TileJSON>(&yaml).unwrap(), tj);
+
+        let tj: TileJSON = serde_yaml::from_str(
+            "tilejson: 3.0.0\ntiles: [x]\nmaxzoom: 14\ncenter: [1, 2, 3]\nvector_layers:\n  - id: a\n    fields: {}\n    minzoom: 3\n",
+        )
+        .unwrap();
+        assert_eq!(tj.maxzoom.unwrap(), 14);
+        assert_eq!(tj.center.unwrap().zoom_level(), Some(3));
+        assert_eq!(tj.vector_layers.unwrap()[0].minzoom.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let tj = full_document();
+        let toml = toml::to_string(&tj).unwrap();
+        assert_eq!(toml::from_str::<TileJSON>(&toml).unwrap(), tj);
+    }
+
+    #[test]
+    fn test_cbor_round_trip() {
+        let tj = full_document();
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&tj, &mut cbor).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<TileJSON, _>(cbor.as_slice()).unwrap(),
+            tj
+        );
+
+        // CBOR is a binary format, so floats must be encoded as floats, but zooms may be integers
+        let value = serde_json::json!({"tilejson": "3.0.0", "tiles": ["x"], "bounds": [-10.0, -10.0, 10.0, 10.0],
+            "center": [1.0, 2.0, 3.0], "bearing": 90.0, "maxzoom": 14});
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&value, &mut cbor).unwrap();
+        let tj: TileJSON = ciborium::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(tj.bounds, Some(Bounds::new(-10.0, -10.0, 10.0, 10.0)));
+        assert_eq!(tj.center, Some(Center::new(1.0, 2.0, 3)));
+        assert_eq!(tj.bearing.map(|v| v.to_string()), Some("90".to_string()));
+        assert_eq!(tj.maxzoom.unwrap(), 14);
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        // bincode is not self-describing, so floats are read without inspecting their type
+        let bounds = Bounds::new(-10.0, -20.5, 10.0, 20.5);
+        let bytes = bincode::serialize(&bounds).unwrap();
+        assert_eq!(bincode::deserialize::<Bounds>(&bytes).unwrap(), bounds);
+
+        for center in [Center::new(1.0, 2.0, 3), Center::without_zoom(1.5, -2.5)] {
+            let bytes = bincode::serialize(&center).unwrap();
+            assert_eq!(bincode::deserialize::<Center>(&bytes).unwrap(), center);
+        }
+    }
 }
@@ -0,0 +1,496 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Layer statistics in the `tilestats` format emitted by
+/// [tippecanoe](https://github.com/felt/tippecanoe) and
+/// [mapbox-geostats](https://github.com/mapbox/mapbox-geostats).
+///
+/// This is not part of the `TileJSON` spec, but is commonly stored in the top-level
+/// `tilestats` key, and is used by styling tools to pick value ranges and color ramps.
+///
+/// ```json
+/// {
+///   "layerCount": 1,
+///   "layers": [
+///     {
+///       "layer": "countries",
+///       "count": 250,
+///       "geometry": "Polygon",
+///       "attributeCount": 2,
+///       "attributes": [
+///         { "attribute": "name", "count": 2, "type": "string", "values": ["Norway", "Peru"] },
+///         { "attribute": "pop", "count": 2, "type": "number", "values": [5, 33], "min": 5, "max": 33 }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Generators omit some of the keys, so all of them are optional when parsing,
+/// and missing values are set to their defaults.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct Tilestats {
+    /// Number of layers in the tileset.
+    pub layer_count: u64,
+
+    /// Statistics for each layer.
+    pub layers: Vec<TilestatsLayer>,
+}
+
+/// Statistics of a single layer, part of [`Tilestats`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct TilestatsLayer {
+    /// The layer id, same as the [`VectorLayer::id`](crate::VectorLayer::id).
+    pub layer: String,
+
+    /// Number of features in the layer.
+    pub count: u64,
+
+    /// The most common geometry type of the layer's features.
+    pub geometry: GeometryType,
+
+    /// Number of unique attribute names in the layer.
+    /// May be larger than the length of `attributes` if some attributes were omitted.
+    pub attribute_count: u64,
+
+    /// Statistics for each attribute.
+    pub attributes: Vec<TilestatsAttribute>,
+}
+
+/// Statistics of a single feature attribute, part of [`TilestatsLayer`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(default)]
+pub struct TilestatsAttribute {
+    /// Name of the attribute.
+    pub attribute: String,
+
+    /// Number of unique values of this attribute.
+    /// May be larger than the length of `values` if some values were omitted.
+    pub count: u64,
+
+    /// The type of the attribute values.
+    #[serde(rename = "type")]
+    pub attr_type: AttributeType,
+
+    /// A sample of unique values of this attribute.
+    pub values: Vec<Value>,
+
+    /// The smallest numeric value, if any of the values were numbers.
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min: Option<f64>,
+
+    /// The largest numeric value, if any of the values were numbers.
+    #[serde(
+        default,
+        deserialize_with = "crate::number::deserialize_opt_f64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max: Option<f64>,
+}
+
+/// Geometry type of the features in a layer.
+///
+/// Values are written back exactly as they were read, so multi-geometries and
+/// unrecognized values like `GeometryCollection` survive a round trip.
+///
+/// ```
+/// # use tilejson::GeometryType;
+/// assert_eq!(GeometryType::from("MultiPolygon".to_string()).single(), GeometryType::Polygon);
+/// assert_eq!(GeometryType::from("raster".to_string()).as_str(), "raster");
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Default, Hash, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
+pub enum GeometryType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    /// The geometry is not known, written as `Unknown`
+    #[default]
+    Unknown,
+    /// Any other or unrecognized value
+    Other(String),
+}
+
+impl GeometryType {
+    /// Get the geometry type name, as written to the `geometry` field.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Point => "Point",
+            Self::LineString => "LineString",
+            Self::Polygon => "Polygon",
+            Self::MultiPoint => "MultiPoint",
+            Self::MultiLineString => "MultiLineString",
+            Self::MultiPolygon => "MultiPolygon",
+            Self::Unknown => "Unknown",
+            Self::Other(v) => v,
+        }
+    }
+
+    /// Get the single-part counterpart of a multi-geometry, or a copy of any other value.
+    #[must_use]
+    pub fn single(&self) -> Self {
+        match self {
+            Self::MultiPoint => Self::Point,
+            Self::MultiLineString => Self::LineString,
+            Self::MultiPolygon => Self::Polygon,
+            v => v.clone(),
+        }
+    }
+
+    /// Check if this is one of the point, line, or polygon types, single or multi-part.
+    #[must_use]
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown | Self::Other(_))
+    }
+}
+
+impl From<String> for GeometryType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Point" => Self::Point,
+            "LineString" => Self::LineString,
+            "Polygon" => Self::Polygon,
+            "MultiPoint" => Self::MultiPoint,
+            "MultiLineString" => Self::MultiLineString,
+            "MultiPolygon" => Self::MultiPolygon,
+            "Unknown" => Self::Unknown,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<GeometryType> for String {
+    fn from(value: GeometryType) -> Self {
+        match value {
+            GeometryType::Other(v) => v,
+            v => v.as_str().to_string(),
+        }
+    }
+}
+
+impl Display for GeometryType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Type of the attribute values, as reported by [`TilestatsAttribute::attr_type`].
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Copy, Clone, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum AttributeType {
+    String,
+    Number,
+    Boolean,
+    Null,
+    /// Values of more than one type, also assumed if the type is missing
+    #[default]
+    Mixed,
+}
+
+/// A single feature used by [`Tilestats::compute`] to collect statistics.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FeatureSample<'a> {
+    /// The id of the layer containing this feature.
+    pub layer: &'a str,
+    /// Geometry type of the feature.
+    pub geometry: GeometryType,
+    /// Feature properties.
+    pub properties: &'a Map<String, Value>,
+}
+
+impl<'a> FeatureSample<'a> {
+    #[must_use]
+    pub fn new(layer: &'a str, geometry: GeometryType, properties: &'a Map<String, Value>) -> Self {
+        Self {
+            layer,
+            geometry,
+            properties,
+        }
+    }
+}
+
+impl Tilestats {
+    /// Maximum number of attributes reported per layer, same as in mapbox-geostats.
+    pub const MAX_ATTRIBUTES: usize = 1000;
+
+    /// Maximum number of sample values reported per attribute, same as in mapbox-geostats.
+    pub const MAX_VALUES: usize = 100;
+
+    /// Compute layer statistics from a set of features.
+    /// Layers are reported in the order they were first seen.
+    ///
+    /// ```
+    /// # use tilejson::{AttributeType, FeatureSample, GeometryType, Tilestats};
+    /// let props = serde_json::json!({ "name": "Peru", "pop": 33 });
+    /// let props = props.as_object().unwrap();
+    /// let stats = Tilestats::compute([
+    ///     FeatureSample::new("countries", GeometryType::Polygon, props),
+    /// ]);
+    /// assert_eq!(stats.layer_count, 1);
+    /// let layer = stats.layer("countries").unwrap();
+    /// assert_eq!(layer.count, 1);
+    /// assert_eq!(layer.attribute("pop").unwrap().attr_type, AttributeType::Number);
+    /// ```
+    pub fn compute<'a, I>(features: I) -> Self
+    where
+        I: IntoIterator<Item = FeatureSample<'a>>,
+    {
+        let mut layers: Vec<LayerAccumulator> = Vec::new();
+        for feature in features {
+            let idx = layers
+                .iter()
+                .position(|v| v.layer == feature.layer)
+                .unwrap_or_else(|| {
+                    layers.push(LayerAccumulator::new(feature.layer));
+                    layers.len() - 1
+                });
+            layers[idx].add(&feature);
+        }
+        let layers: Vec<_> = layers.into_iter().map(LayerAccumulator::finish).collect();
+        Self {
+            layer_count: layers.len() as u64,
+            layers,
+        }
+    }
+
+    /// Find statistics for the layer with the given id.
+    #[must_use]
+    pub fn layer(&self, id: &str) -> Option<&TilestatsLayer> {
+        self.layers.iter().find(|v| v.layer == id)
+    }
+}
+
+impl TilestatsLayer {
+    /// Find statistics for the attribute with the given name.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&TilestatsAttribute> {
+        self.attributes.iter().find(|v| v.attribute == name)
+    }
+}
+
+struct LayerAccumulator {
+    layer: String,
+    count: u64,
+    geometries: BTreeMap<GeometryType, u64>,
+    attributes: BTreeMap<String, AttributeAccumulator>,
+}
+
+impl LayerAccumulator {
+    fn new(layer: &str) -> Self {
+        Self {
+            layer: layer.to_string(),
+            count: 0,
+            geometries: BTreeMap::new(),
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    fn add(&mut self, feature: &FeatureSample<'_>) {
+        self.count += 1;
+        *self.geometries.entry(feature.geometry.clone()).or_default() += 1;
+        for (name, value) in feature.properties {
+            self.attributes.entry(name.clone()).or_default().add(value);
+        }
+    }
+
+    fn finish(self) -> TilestatsLayer {
+        // Use the most common geometry, preferring the first one in the enum order on ties
+        let geometry = self
+            .geometries
+            .iter()
+            .rev()
+            .max_by_key(|(_, count)| **count)
+            .map_or(GeometryType::Unknown, |(geom, _)| geom.clone());
+        TilestatsLayer {
+            layer: self.layer,
+            count: self.count,
+            geometry,
+            attribute_count: self.attributes.len() as u64,
+            attributes: self
+                .attributes
+                .into_iter()
+                .take(Tilestats::MAX_ATTRIBUTES)
+                .map(|(name, attr)| attr.finish(name))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AttributeAccumulator {
+    /// JSON representation of each unique value
+    unique: BTreeSet<String>,
+    values: Vec<Value>,
+    attr_type: Option<AttributeType>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl AttributeAccumulator {
+    fn add(&mut self, value: &Value) {
+        let value_type = match value {
+            Value::Null => AttributeType::Null,
+            Value::Bool(_) => AttributeType::Boolean,
+            Value::Number(v) => {
+                if let Some(v) = v.as_f64() {
+                    self.min = Some(self.min.map_or(v, |min| min.min(v)));
+                    self.max = Some(self.max.map_or(v, |max| max.max(v)));
+                }
+                AttributeType::Number
+            }
+            Value::String(_) | Value::Array(_) | Value::Object(_) => AttributeType::String,
+        };
+        self.attr_type = match self.attr_type {
+            Some(t) if t != value_type => Some(AttributeType::Mixed),
+            _ => Some(value_type),
+        };
+        if self.unique.insert(value.to_string()) && self.values.len() < Tilestats::MAX_VALUES {
+            self.values.push(match value {
+                Value::Array(_) | Value::Object(_) => Value::String(value.to_string()),
+                _ => value.clone(),
+            });
+        }
+    }
+
+    fn finish(self, attribute: String) -> TilestatsAttribute {
+        TilestatsAttribute {
+            attribute,
+            count: self.unique.len() as u64,
+            attr_type: self.attr_type.unwrap_or(AttributeType::Null),
+            values: self.values,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let stats: Tilestats = serde_json::from_value(json!({
+            "layerCount": 1,
+            "layers": [{
+                "layer": "pois",
+                "count": 3,
+                "geometry": "Point",
+                "attributeCount": 1,
+                "attributes": [{
+                    "attribute": "rank",
+                    "count": 2,
+                    "type": "number",
+                    "values": [1, 5],
+                    "min": 1,
+                    "max": 5
+                }]
+            }]
+        }))
+        .unwrap();
+        let layer = stats.layer("pois").unwrap();
+        assert_eq!(layer.geometry, GeometryType::Point);
+        let attr = layer.attribute("rank").unwrap();
+        assert_eq!(attr.attr_type, AttributeType::Number);
+        assert_eq!(attr.min, Some(1.0));
+        assert_eq!(attr.max, Some(5.0));
+        assert!(stats.layer("roads").is_none());
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let stats: Tilestats = serde_json::from_value(json!({"layerCount": 2})).unwrap();
+        assert_eq!(stats.layer_count, 2);
+        assert!(stats.layers.is_empty());
+
+        let stats: Tilestats = serde_json::from_value(json!({
+            "layers": [{"layer": "pois", "attributes": [{"attribute": "rank"}]}]
+        }))
+        .unwrap();
+        let layer = stats.layer("pois").unwrap();
+        assert_eq!(layer.count, 0);
+        assert_eq!(layer.geometry, GeometryType::default());
+        let attr = layer.attribute("rank").unwrap();
+        assert_eq!(attr.attr_type, AttributeType::Mixed);
+        assert!(attr.values.is_empty());
+    }
+
+    #[test]
+    fn test_geometry() {
+        let parse = |v: &str| serde_json::from_value::<GeometryType>(json!(v)).unwrap();
+        assert_eq!(parse("Point"), GeometryType::Point);
+        assert_eq!(parse("MultiPolygon"), GeometryType::MultiPolygon);
+        assert_eq!(parse("Unknown"), GeometryType::Unknown);
+        assert_eq!(
+            parse("GeometryCollection"),
+            GeometryType::Other("GeometryCollection".to_string())
+        );
+        for value in ["Point", "MultiPoint", "MultiPolygon", "Unknown", "raster"] {
+            assert_eq!(serde_json::to_value(parse(value)).unwrap(), json!(value));
+        }
+    }
+
+    #[test]
+    fn test_compute() {
+        let f1 = json!({ "name": "a", "rank": 3, "open": true });
+        let f2 = json!({ "name": "b", "rank": -1, "open": "yes" });
+        let f3 = json!({ "name": "a" });
+        let (f1, f2, f3) = (
+            f1.as_object().unwrap(),
+            f2.as_object().unwrap(),
+            f3.as_object().unwrap(),
+        );
+        let stats = Tilestats::compute([
+            FeatureSample::new("pois", GeometryType::Point, f1),
+            FeatureSample::new("pois", GeometryType::Polygon, f2),
+            FeatureSample::new("pois", GeometryType::Point, f3),
+            FeatureSample::new("roads", GeometryType::LineString, f3),
+        ]);
+
+        assert_eq!(stats.layer_count, 2);
+        assert_eq!(stats.layers[0].layer, "pois");
+        assert_eq!(stats.layers[1].layer, "roads");
+
+        let pois = &stats.layers[0];
+        assert_eq!(pois.count, 3);
+        assert_eq!(pois.geometry, GeometryType::Point);
+        assert_eq!(pois.attribute_count, 3);
+
+        let name = pois.attribute("name").unwrap();
+        assert_eq!(name.count, 2);
+        assert_eq!(name.attr_type, AttributeType::String);
+        assert_eq!(name.values, vec![json!("a"), json!("b")]);
+
+        let rank = pois.attribute("rank").unwrap();
+        assert_eq!(rank.attr_type, AttributeType::Number);
+        assert_eq!((rank.min, rank.max), (Some(-1.0), Some(3.0)));
+
+        assert_eq!(
+            pois.attribute("open").unwrap().attr_type,
+            AttributeType::Mixed
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use thiserror::Error;
+
+use crate::bounds::Bounds;
+use crate::center::Center;
+use crate::tile_size::TileSize;
+use crate::zoom::MAX_ZOOM;
+use crate::TileJSON;
+
+/// A violation of the `TileJSON` spec, as reported by [`TileJSON::validate`].
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ValidationError {
+    #[error("tiles must contain at least one endpoint")]
+    NoTiles,
+    #[error("minzoom {0} must not be greater than maxzoom {1}")]
+    InvalidZoomRange(u8, u8),
+    #[error("bounds {0} must be within [-180, -90, 180, 90], with bottom not greater than top")]
+    InvalidBounds(Bounds),
+    #[error("bounds {0} must not cross the antimeridian")]
+    BoundsCrossAntimeridian(Bounds),
+    #[error("center {0} must be within bounds {1}")]
+    CenterOutsideBounds(Center, Bounds),
+    #[error("center zoom {0} must be between minzoom {1} and maxzoom {2}")]
+    CenterZoomOutOfRange(f64, u8, u8),
+    #[error("scheme must be either \"xyz\" or \"tms\", but is {0:?}")]
+    InvalidScheme(String),
+    #[error(
+        "vector layer {0} zoom range {1}..={2} must be within the tileset zoom range {3}..={4}"
+    )]
+    InvalidLayerZoomRange(String, u8, u8, u8, u8),
+    #[error("tileSize {0} must be a power of two between 64 and 4096")]
+    InvalidTileSize(TileSize),
+}
+
+impl TileJSON {
+    /// Check that the document follows the constraints of the `TileJSON` spec.
+    ///
+    /// Missing optional values are assumed to have their spec-defined defaults.
+    ///
+    /// # Errors
+    /// Returns the first [`ValidationError`] found.
+    ///
+    /// ```
+    /// # use tilejson::{tilejson, ValidationError};
+    /// let tj = tilejson! { "https://example.com/".to_string(), minzoom: 5, maxzoom: 2 };
+    /// assert_eq!(tj.validate(), Err(ValidationError::InvalidZoomRange(5, 2)));
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.tiles.is_empty() {
+            return Err(ValidationError::NoTiles);
+        }
+        let minzoom = self.minzoom.map_or(0, u8::from);
+        let maxzoom = self.maxzoom.map_or(MAX_ZOOM, u8::from);
+        if minzoom > maxzoom {
+            return Err(ValidationError::InvalidZoomRange(minzoom, maxzoom));
+        }
+
+        if let Some(bounds) = self.bounds {
+            if !Bounds::MAX.contains(bounds.left, bounds.bottom)
+                || !Bounds::MAX.contains(bounds.right, bounds.top)
+                || bounds.bottom > bounds.top
+            {
+                return Err(ValidationError::InvalidBounds(bounds));
+            }
+            if bounds.crosses_antimeridian() {
+                return Err(ValidationError::BoundsCrossAntimeridian(bounds));
+            }
+        }
+
+        if let Some(center) = self.center {
+            let bounds = self.bounds.unwrap_or_default();
+            if !bounds.contains(center.longitude, center.latitude) {
+                return Err(ValidationError::CenterOutsideBounds(center, bounds));
+            }
+            if let Some(zoom) = center.zoom {
+                if !(f64::from(minzoom)..=f64::from(maxzoom)).contains(&zoom) {
+                    return Err(ValidationError::CenterZoomOutOfRange(
+                        zoom, minzoom, maxzoom,
+                    ));
+                }
+            }
+        }
+
+        if let Some(scheme) = &self.scheme {
+            if scheme != "xyz" && scheme != "tms" {
+                return Err(ValidationError::InvalidScheme(scheme.clone()));
+            }
+        }
+
+        if let Some(tile_size) = self.tile_size {
+            if !tile_size.is_standard() {
+                return Err(ValidationError::InvalidTileSize(tile_size));
+            }
+        }
+
+        for layer in self.vector_layers.iter().flatten() {
+            let layer_min = layer.minzoom.map_or(minzoom, u8::from);
+            let layer_max = layer.maxzoom.map_or(maxzoom, u8::from);
+            if layer_min > layer_max || layer_min < minzoom || layer_max > maxzoom {
+                return Err(ValidationError::InvalidLayerZoomRange(
+                    layer.id.clone(),
+                    layer_min,
+                    layer_max,
+                    minzoom,
+                    maxzoom,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{tilejson, VectorLayer, Zoom};
+
+    #[test]
+    fn test_valid() {
+        let tj = tilejson! {
+            "https://example.com/".to_string(),
+            bounds: Bounds::new(-10., -10., 10., 10.),
+            center: Center::new(0., 0., 3),
+            minzoom: 0,
+            maxzoom: 14,
+            scheme: "tms".to_string(),
+        };
+        assert_eq!(tj.validate(), Ok(()));
+
+        let mut tj = tilejson! { "https://example.com/".to_string() };
+        tj.set_missing_defaults();
+        assert_eq!(tj.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let tj = || tilejson! { "https://example.com/".to_string(), maxzoom: 14 };
+        let err = |tj: TileJSON| tj.validate().unwrap_err();
+
+        let mut v = tj();
+        v.tiles.clear();
+        assert_eq!(err(v), ValidationError::NoTiles);
+
+        let mut v = tj();
+        v.bounds = Some(Bounds::new(-200., 0., 0., 10.));
+        assert!(matches!(err(v), ValidationError::InvalidBounds(_)));
+
+        let mut v = tj();
+        v.bounds = Some(Bounds::new(170., 0., -170., 10.));
+        assert!(matches!(
+            err(v),
+            ValidationError::BoundsCrossAntimeridian(_)
+        ));
+
+        let mut v = tj();
+        v.bounds = Some(Bounds::new(0., 0., 10., 10.));
+        v.center = Some(Center::new(20., 5., 3));
+        assert!(matches!(err(v), ValidationError::CenterOutsideBounds(..)));
+
+        let mut v = tj();
+        v.center = Some(Center::new(0., 0., 15));
+        assert_eq!(err(v), ValidationError::CenterZoomOutOfRange(15.0, 0, 14));
+
+        let mut v = tj();
+        v.center = Some(Center::from((0., 0., 14.5)));
+        assert_eq!(err(v), ValidationError::CenterZoomOutOfRange(14.5, 0, 14));
+
+        let mut v = tj();
+        v.scheme = Some("wmts".to_string());
+        assert_eq!(err(v), ValidationError::InvalidScheme("wmts".to_string()));
+
+        let mut v = tj();
+        v.tile_size = TileSize::new(300).ok();
+        assert_eq!(
+            err(v),
+            ValidationError::InvalidTileSize(TileSize::new(300).unwrap())
+        );
+
+        let mut v = tj();
+        let mut layer = VectorLayer::new("roads".to_string(), BTreeMap::new());
+        layer.maxzoom = Zoom::new(16).ok();
+        v.vector_layers = Some(vec![layer]);
+        assert_eq!(
+            err(v),
+            ValidationError::InvalidLayerZoomRange("roads".to_string(), 0, 16, 0, 14)
+        );
+    }
+}
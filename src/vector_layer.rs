@@ -3,6 +3,9 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::tilestats::{GeometryType, Tilestats, TilestatsLayer};
+use crate::zoom::Zoom;
+
 /// Each object describes one layer of vector tile data.
 ///
 /// A `vector_layer` object MUST contain the id and fields keys, and MAY contain the description,
@@ -53,6 +56,8 @@ use serde_json::Value;
 ///
 /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#33-vector_layers>
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorLayer {
     /// A string value representing the the layer id.
     ///
@@ -79,14 +84,22 @@ pub struct VectorLayer {
     /// maxzoom MUST be less than or equal to the set of tiles' maxzoom.
     /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#334-minzoom-and-maxzoom>
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maxzoom: Option<u8>,
+    pub maxzoom: Option<Zoom>,
 
     /// An integer representing the lowest zoom level whose tiles this layer appears in.
     ///
     /// minzoom MUST be greater than or equal to the set of tiles' minzoom.
     /// See <https://github.com/mapbox/tilejson-spec/tree/master/3.0.0#334-minzoom-and-maxzoom>
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minzoom: Option<u8>,
+    pub minzoom: Option<Zoom>,
+
+    /// The geometry type of the features in this layer.
+    ///
+    /// This is not part of the `TileJSON` spec, but is emitted by many tile generators.
+    /// Multi-geometries render the same as their single counterparts,
+    /// and any unrecognized value is kept as [`GeometryType::Other`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<GeometryType>,
 
     /// Any unrecognized fields will be stored here.
     #[serde(flatten)]
@@ -102,7 +115,79 @@ impl VectorLayer {
             description: None,
             maxzoom: None,
             minzoom: None,
+            geometry: None,
             other: BTreeMap::default(),
         }
     }
+
+    /// Find the statistics of this layer in the given [`Tilestats`].
+    #[must_use]
+    pub fn stats<'a>(&self, tilestats: &'a Tilestats) -> Option<&'a TilestatsLayer> {
+        tilestats.layer(&self.id)
+    }
+
+    /// Pick a render type suitable for this layer based on its [`geometry`](Self::geometry).
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use tilejson::{GeometryType, RenderType, VectorLayer};
+    /// let mut layer = VectorLayer::new("roads".to_string(), BTreeMap::new());
+    /// assert_eq!(layer.render_type(), None);
+    /// layer.geometry = Some(GeometryType::LineString);
+    /// assert_eq!(layer.render_type(), Some(RenderType::Line));
+    /// ```
+    #[must_use]
+    pub fn render_type(&self) -> Option<RenderType> {
+        self.geometry.as_ref().and_then(GeometryType::render_type)
+    }
+}
+
+/// A style layer type used to render features of a given geometry,
+/// as defined by the `type` property of `MapLibre` and Mapbox style layers.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Copy, Clone, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderType {
+    Circle,
+    Line,
+    Fill,
+}
+
+impl GeometryType {
+    /// Pick a render type suitable for this geometry, or `None` if the geometry is unknown.
+    #[must_use]
+    pub fn render_type(&self) -> Option<RenderType> {
+        match self.single() {
+            Self::Point => Some(RenderType::Circle),
+            Self::LineString => Some(RenderType::Line),
+            Self::Polygon => Some(RenderType::Fill),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry() {
+        let layer: VectorLayer =
+            serde_json::from_str(r#"{"id":"a","fields":{},"geometry":"MultiPolygon"}"#).unwrap();
+        assert_eq!(layer.geometry, Some(GeometryType::MultiPolygon));
+        assert_eq!(layer.render_type(), Some(RenderType::Fill));
+        assert!(layer.other.is_empty());
+        assert_eq!(
+            serde_json::to_string(&layer).unwrap(),
+            r#"{"id":"a","fields":{},"geometry":"MultiPolygon"}"#
+        );
+
+        let json = r#"{"id":"a","fields":{},"geometry":"raster"}"#;
+        let layer: VectorLayer = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            layer.geometry,
+            Some(GeometryType::Other("raster".to_string()))
+        );
+        assert_eq!(layer.render_type(), None);
+        assert_eq!(serde_json::to_string(&layer).unwrap(), json);
+    }
 }
@@ -0,0 +1,277 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Map;
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::TileJSON;
+
+/// The maximum zoom level allowed by the spec.
+pub const MAX_ZOOM: u8 = 30;
+
+/// A zoom level between 0 and [`MAX_ZOOM`], serialized as an integer.
+///
+/// ```
+/// # use tilejson::{Zoom, ZoomError};
+/// assert_eq!(Zoom::new(14).unwrap().get(), 14);
+/// assert_eq!(Zoom::new(200), Err(ZoomError::OutOfRange(200)));
+/// assert!(serde_json::from_str::<Zoom>("200").is_err());
+/// ```
+#[derive(
+    Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Copy, Clone,
+)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct Zoom(u8);
+
+impl Zoom {
+    pub const MIN: Self = Self(0);
+    pub const MAX: Self = Self(MAX_ZOOM);
+
+    /// Create a zoom level, checking that it is not greater than [`MAX_ZOOM`].
+    ///
+    /// # Errors
+    /// Returns [`ZoomError::OutOfRange`] if the value is greater than [`MAX_ZOOM`].
+    pub const fn new(zoom: u8) -> Result<Self, ZoomError> {
+        if zoom <= MAX_ZOOM {
+            Ok(Self(zoom))
+        } else {
+            Err(ZoomError::OutOfRange(zoom))
+        }
+    }
+
+    /// Get the zoom level as an integer.
+    #[must_use]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Add a signed offset, saturating at [`Zoom::MIN`] and [`Zoom::MAX`].
+    ///
+    /// ```
+    /// # use tilejson::Zoom;
+    /// assert_eq!(Zoom::new(3).unwrap().saturating_add_signed(-5), Zoom::MIN);
+    /// assert_eq!(Zoom::new(29).unwrap().saturating_add_signed(2), Zoom::MAX);
+    /// ```
+    #[must_use]
+    pub fn saturating_add_signed(self, offset: i8) -> Self {
+        Self(self.0.saturating_add_signed(offset).min(MAX_ZOOM))
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum ZoomError {
+    #[error("Zoom level {0} must be between 0 and {max}", max = MAX_ZOOM)]
+    OutOfRange(u8),
+    #[error("Zoom range minimum {0} must not be greater than maximum {1}")]
+    InvalidRange(Zoom, Zoom),
+    /// Wrapped error from the `parse::<u8>()`
+    #[error(transparent)]
+    ParseError(#[from] ParseIntError),
+}
+
+impl TryFrom<u8> for Zoom {
+    type Error = ZoomError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Zoom> for u8 {
+    fn from(value: Zoom) -> Self {
+        value.0
+    }
+}
+
+impl From<Zoom> for f64 {
+    fn from(value: Zoom) -> Self {
+        f64::from(value.0)
+    }
+}
+
+impl PartialEq<u8> for Zoom {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Display for Zoom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Zoom {
+    type Err = ZoomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.trim().parse()?)
+    }
+}
+
+/// An inclusive range of zoom levels, with the minimum not greater than the maximum.
+///
+/// ```
+/// # use tilejson::{Zoom, ZoomRange};
+/// let range = ZoomRange::new(Zoom::new(2).unwrap(), Zoom::new(5).unwrap()).unwrap();
+/// assert_eq!(range.to_string(), "2..=5");
+/// assert_eq!(range.iter().map(Zoom::get).collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+/// assert!(range.contains(Zoom::new(3).unwrap()));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct ZoomRange {
+    min: Zoom,
+    max: Zoom,
+}
+
+impl ZoomRange {
+    /// All zoom levels, from [`Zoom::MIN`] to [`Zoom::MAX`].
+    pub const FULL: Self = Self {
+        min: Zoom::MIN,
+        max: Zoom::MAX,
+    };
+
+    /// Create a zoom range.
+    ///
+    /// # Errors
+    /// Returns [`ZoomError::InvalidRange`] if `min` is greater than `max`.
+    pub fn new(min: Zoom, max: Zoom) -> Result<Self, ZoomError> {
+        if min <= max {
+            Ok(Self { min, max })
+        } else {
+            Err(ZoomError::InvalidRange(min, max))
+        }
+    }
+
+    #[must_use]
+    pub fn min(self) -> Zoom {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(self) -> Zoom {
+        self.max
+    }
+
+    #[must_use]
+    pub fn contains(self, zoom: Zoom) -> bool {
+        self.min <= zoom && zoom <= self.max
+    }
+
+    /// Check if the other range is entirely within this range.
+    #[must_use]
+    pub fn contains_range(self, other: Self) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    /// Get the zoom levels present in both ranges, or `None` if the ranges do not overlap.
+    ///
+    /// ```
+    /// # use tilejson::{Zoom, ZoomRange};
+    /// let range = |a, b| ZoomRange::new(Zoom::new(a).unwrap(), Zoom::new(b).unwrap()).unwrap();
+    /// assert_eq!(range(0, 10).intersection(range(5, 14)), Some(range(5, 10)));
+    /// assert_eq!(range(0, 4).intersection(range(5, 14)), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::new(self.min.max(other.min), self.max.min(other.max)).ok()
+    }
+
+    /// Get the nearest zoom level within this range.
+    #[must_use]
+    pub fn clamp(self, zoom: Zoom) -> Zoom {
+        zoom.clamp(self.min, self.max)
+    }
+
+    /// Iterate over all zoom levels in this range, in increasing order.
+    pub fn iter(self) -> ZoomRangeIter {
+        self.into_iter()
+    }
+}
+
+impl Default for ZoomRange {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+impl Display for ZoomRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.min, self.max)
+    }
+}
+
+/// Iterator over the zoom levels of a [`ZoomRange`].
+pub type ZoomRangeIter = Map<RangeInclusive<u8>, fn(u8) -> Zoom>;
+
+impl IntoIterator for ZoomRange {
+    type Item = Zoom;
+    type IntoIter = ZoomRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (self.min.0..=self.max.0).map(Zoom)
+    }
+}
+
+impl TileJSON {
+    /// Get the zoom range of the tileset, using the spec defaults of 0 and 30
+    /// for a missing `minzoom` and `maxzoom`. Returns `None` if `minzoom` is greater than `maxzoom`.
+    ///
+    /// ```
+    /// # use tilejson::tilejson;
+    /// let tj = tilejson! { "https://example.com/".to_string(), maxzoom: 14 };
+    /// assert_eq!(tj.zoom_range().unwrap().to_string(), "0..=14");
+    /// ```
+    #[must_use]
+    pub fn zoom_range(&self) -> Option<ZoomRange> {
+        ZoomRange::new(
+            self.minzoom.unwrap_or(Zoom::MIN),
+            self.maxzoom.unwrap_or(Zoom::MAX),
+        )
+        .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zoom(v: u8) -> Zoom {
+        Zoom::new(v).unwrap()
+    }
+
+    #[test]
+    fn test_zoom() {
+        assert_eq!(Zoom::MAX, 30);
+        assert_eq!(Zoom::new(31), Err(ZoomError::OutOfRange(31)));
+        assert_eq!(
+            ZoomError::OutOfRange(31).to_string(),
+            "Zoom level 31 must be between 0 and 30"
+        );
+        assert_eq!(Zoom::from_str(" 7 "), Ok(zoom(7)));
+        assert!(matches!(Zoom::from_str("a"), Err(ZoomError::ParseError(_))));
+        assert_eq!(serde_json::to_string(&zoom(7)).unwrap(), "7");
+        assert_eq!(serde_json::from_str::<Zoom>("7").unwrap(), zoom(7));
+        assert!(serde_json::from_str::<Zoom>("31").is_err());
+        assert!(serde_json::from_str::<Zoom>("-1").is_err());
+    }
+
+    #[test]
+    fn test_range() {
+        let range = |a, b| ZoomRange::new(zoom(a), zoom(b)).unwrap();
+        assert_eq!(
+            ZoomRange::new(zoom(5), zoom(4)),
+            Err(ZoomError::InvalidRange(zoom(5), zoom(4)))
+        );
+        assert_eq!(ZoomRange::FULL.iter().len(), 31);
+        assert_eq!(range(3, 3).iter().collect::<Vec<_>>(), vec![zoom(3)]);
+        assert!(!range(3, 5).contains(zoom(6)));
+        assert!(range(0, 10).contains_range(range(2, 10)));
+        assert!(!range(0, 10).contains_range(range(2, 11)));
+        assert_eq!(range(3, 5).intersection(range(5, 8)), Some(range(5, 5)));
+        assert_eq!(range(3, 5).clamp(zoom(10)), zoom(5));
+    }
+}
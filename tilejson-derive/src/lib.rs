@@ -0,0 +1,209 @@
+//! # `TileJSON` derive
+//!
+//! Derive macro for the `tilejson::Extension` trait. Use it via the `derive` feature
+//! of the `tilejson` crate, rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, ExprPath, Fields, LitStr};
+
+/// Implement `tilejson::Extension`, `serde::Serialize`, and `serde::Deserialize` for a struct,
+/// mapping each field to a key of `TileJSON::other` or `VectorLayer::other`.
+///
+/// Do not derive `Serialize` or `Deserialize` on the same struct, because this macro
+/// implements them to read and write only the declared keys.
+///
+/// Container attributes:
+/// * `#[tilejson(prefix = "x-acme-")]` — prepend to every key.
+/// * `#[tilejson(validate = "path::to::fn")]` — call `fn(&Self) -> Result<(), String>`
+///   from `Extension::validate`.
+///
+/// Field attributes:
+/// * `#[tilejson(rename = "maskLevel")]` — use this key instead of the field name.
+/// * `#[tilejson(default)]` — use `Default::default()` if the key is missing.
+///   `Option` fields are always `None` if the key is missing.
+/// * `#[tilejson(validate = "path::to::fn")]` — call `fn(&FieldType) -> Result<(), String>`
+///   from `Extension::validate`. The error message is prefixed with the key.
+///
+/// `None` values and any other values serialized as `null` are not written to `other`.
+///
+/// ```
+/// use tilejson::{tilejson, TileJsonExtension};
+///
+/// fn check_level(level: &u8) -> Result<(), String> {
+///     if *level <= 30 { Ok(()) } else { Err(format!("{level} is not a zoom level")) }
+/// }
+///
+/// #[derive(TileJsonExtension, PartialEq, Debug)]
+/// #[tilejson(prefix = "x-acme-")]
+/// struct Acme {
+///     #[tilejson(rename = "maskLevel", validate = "check_level")]
+///     mask_level: u8,
+///     owner: Option<String>,
+/// }
+///
+/// let mut tj = tilejson! { "https://example.com/".to_string() };
+/// tj.set_ext(&Acme { mask_level: 8, owner: None }).unwrap();
+/// assert_eq!(serde_json::to_string(&tj.other).unwrap(), r#"{"x-acme-maskLevel":8}"#);
+/// assert_eq!(tj.ext::<Acme>().unwrap(), Some(Acme { mask_level: 8, owner: None }));
+/// ```
+#[proc_macro_derive(TileJsonExtension, attributes(tilejson))]
+pub fn derive_extension(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    default: bool,
+    validate: Option<ExprPath>,
+}
+
+#[derive(Default)]
+struct ContainerAttrs {
+    prefix: String,
+    validate: Option<ExprPath>,
+}
+
+fn parse_path(lit: &LitStr) -> syn::Result<ExprPath> {
+    lit.parse()
+}
+
+fn container_attrs(input: &DeriveInput) -> syn::Result<ContainerAttrs> {
+    let mut result = ContainerAttrs::default();
+    for attr in input.attrs.iter().filter(|v| v.path().is_ident("tilejson")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                result.prefix = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("validate") {
+                result.validate = Some(parse_path(&meta.value()?.parse()?)?);
+            } else {
+                return Err(meta.error("unsupported tilejson container attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(result)
+}
+
+fn field_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let mut result = FieldAttrs::default();
+    for attr in field.attrs.iter().filter(|v| v.path().is_ident("tilejson")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                result.default = true;
+            } else if meta.path.is_ident("validate") {
+                result.validate = Some(parse_path(&meta.value()?.parse()?)?);
+            } else {
+                return Err(meta.error("unsupported tilejson field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(result)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "TileJsonExtension does not support generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "TileJsonExtension requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "TileJsonExtension can only be derived for structs",
+            ))
+        }
+    };
+    let container = container_attrs(input)?;
+
+    let mut keys = Vec::new();
+    let mut serialize = Vec::new();
+    let mut deserialize = Vec::new();
+    let mut validate = Vec::new();
+    for field in fields {
+        let attrs = field_attrs(field)?;
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let key = format!(
+            "{}{}",
+            container.prefix,
+            attrs.rename.unwrap_or_else(|| ident.unraw().to_string())
+        );
+        serialize.push(quote! {
+            ::tilejson::__private::insert(&mut map, #key, &self.#ident)
+                .map_err(::tilejson::__private::serde::ser::Error::custom)?;
+        });
+        let default = if attrs.default {
+            quote! { ::core::option::Option::Some(::core::default::Default::default) }
+        } else {
+            quote! { ::core::option::Option::None }
+        };
+        deserialize.push(quote! {
+            #ident: ::tilejson::__private::take(&mut map, #key, #default)?,
+        });
+        if let Some(path) = attrs.validate {
+            validate.push(quote! {
+                #path(&self.#ident).map_err(|e| ::std::format!("{}: {}", #key, e))?;
+            });
+        }
+        keys.push(key);
+    }
+    if let Some(path) = container.validate {
+        validate.push(quote! { #path(self)?; });
+    }
+
+    Ok(quote! {
+        impl ::tilejson::Extension for #name {
+            const KEYS: &'static [&'static str] = &[#(#keys),*];
+
+            fn validate(&self) -> ::core::result::Result<(), ::std::string::String> {
+                #(#validate)*
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl ::tilejson::__private::serde::Serialize for #name {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::tilejson::__private::serde::Serializer,
+            {
+                let mut map = ::tilejson::__private::Map::new();
+                #(#serialize)*
+                ::tilejson::__private::serde::Serialize::serialize(&map, serializer)
+            }
+        }
+
+        impl<'de> ::tilejson::__private::serde::Deserialize<'de> for #name {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: ::tilejson::__private::serde::Deserializer<'de>,
+            {
+                let mut map: ::tilejson::__private::Map =
+                    ::tilejson::__private::serde::Deserialize::deserialize(deserializer)?;
+                ::core::result::Result::Ok(Self {
+                    #(#deserialize)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use serde_json::json;
+use tilejson::{tilejson, Extension, ExtensionRegistry, TileJsonExtension, VectorLayer};
+
+fn not_empty(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        Err("must not be empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn check_range(value: &GeneratorInfo) -> Result<(), String> {
+    if value.min_version > value.max_version {
+        Err("min_version must not exceed max_version".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(TileJsonExtension, PartialEq, Debug)]
+#[tilejson(validate = "check_range")]
+struct GeneratorInfo {
+    #[tilejson(validate = "not_empty")]
+    generator: String,
+    #[tilejson(rename = "generatorOptions")]
+    options: Option<String>,
+    #[tilejson(default)]
+    min_version: u32,
+    #[tilejson(default)]
+    max_version: u32,
+}
+
+#[derive(TileJsonExtension, PartialEq, Debug)]
+#[tilejson(prefix = "x-acme-")]
+struct LayerStyle {
+    r#type: String,
+    #[tilejson(default)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_keys() {
+    assert_eq!(
+        GeneratorInfo::KEYS,
+        &[
+            "generator",
+            "generatorOptions",
+            "min_version",
+            "max_version"
+        ]
+    );
+    assert_eq!(LayerStyle::KEYS, &["x-acme-type", "x-acme-tags"]);
+}
+
+#[test]
+fn test_round_trip() {
+    let mut tj = tilejson! { "https://example.com/".to_string() };
+    tj.other
+        .insert("generator".to_string(), json!("tippecanoe"));
+    let value = tj.ext::<GeneratorInfo>().unwrap().unwrap();
+    assert_eq!(
+        value,
+        GeneratorInfo {
+            generator: "tippecanoe".to_string(),
+            options: None,
+            min_version: 0,
+            max_version: 0,
+        }
+    );
+
+    tj.set_ext(&GeneratorInfo {
+        options: Some("-z14".to_string()),
+        max_version: 2,
+        ..value
+    })
+    .unwrap();
+    assert_eq!(
+        json!(tj.other),
+        json!({"generator": "tippecanoe", "generatorOptions": "-z14", "min_version": 0, "max_version": 2})
+    );
+
+    let mut layer = VectorLayer::new("roads".to_string(), BTreeMap::new());
+    layer
+        .set_ext(&LayerStyle {
+            r#type: "line".to_string(),
+            tags: vec![],
+        })
+        .unwrap();
+    assert_eq!(
+        json!(layer.other),
+        json!({"x-acme-type": "line", "x-acme-tags": []})
+    );
+}
+
+#[test]
+fn test_errors() {
+    let mut tj = tilejson! { "https://example.com/".to_string() };
+    tj.other
+        .insert("generatorOptions".to_string(), json!("-z14"));
+    let err = tj.ext::<GeneratorInfo>().unwrap_err();
+    assert!(
+        err.to_string().ends_with("missing field `generator`"),
+        "{err}"
+    );
+
+    tj.other.insert("generator".to_string(), json!(1));
+    let err = tj.ext::<GeneratorInfo>().unwrap_err();
+    assert!(err.to_string().contains("generator: invalid type"), "{err}");
+
+    let registry = ExtensionRegistry::new().with::<GeneratorInfo>();
+    tj.other.insert("generator".to_string(), json!(""));
+    let err = registry.validate(&tj).unwrap_err();
+    assert!(
+        err.to_string().ends_with("generator: must not be empty"),
+        "{err}"
+    );
+
+    tj.other.insert("generator".to_string(), json!("a"));
+    tj.other.insert("min_version".to_string(), json!(3));
+    let err = registry.validate(&tj).unwrap_err();
+    assert!(
+        err.to_string()
+            .ends_with("min_version must not exceed max_version"),
+        "{err}"
+    );
+}